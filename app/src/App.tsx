@@ -1,13 +1,13 @@
-import { useState, useCallback, useEffect } from 'react';
-import Editor from '@monaco-editor/react';
+import { useState, useCallback, useEffect, useRef } from 'react';
+import Editor, { OnMount } from '@monaco-editor/react';
 import { TokensView, Token } from './components/TokensView';
-import { ASTView } from './components/ASTView';
+import { ASTView, ParseError, Span } from './components/ASTView';
 import { FolderOpen, Save, Play, X } from 'lucide-react';
 
 interface AnalysisResult {
   tokens: Token[];
   ast: any;
-  error?: string;
+  errors: ParseError[];
 }
 
 type Tab = 'tokens' | 'ast';
@@ -27,6 +27,36 @@ console.log(result);`);
   const [panelWidth, setPanelWidth] = useState(750);
   const [isResizing, setIsResizing] = useState(false);
   const [showErrorToast, setShowErrorToast] = useState(false);
+  const editorRef = useRef<Parameters<OnMount>[0] | null>(null);
+  const monacoRef = useRef<Parameters<OnMount>[1] | null>(null);
+  const decorationsRef = useRef<string[]>([]);
+
+  // Destaca no editor o trecho do nó sob o mouse na aba AST
+  const highlightSpan = useCallback((span: Span | null) => {
+    const editor = editorRef.current;
+    if (!editor) return;
+
+    const decorations = span
+      ? [{
+        range: {
+          startLineNumber: span.start_line,
+          startColumn: span.start_column,
+          endLineNumber: span.end_line,
+          endColumn: span.end_column,
+        },
+        options: { className: 'ast-highlight' },
+      }]
+      : [];
+    decorationsRef.current = editor.deltaDecorations(decorationsRef.current, decorations);
+    if (span) {
+      editor.revealRangeInCenterIfOutsideViewport({
+        startLineNumber: span.start_line,
+        startColumn: span.start_column,
+        endLineNumber: span.end_line,
+        endColumn: span.end_column,
+      });
+    }
+  }, []);
 
   const analyzeCode = useCallback(async () => {
     if (!window.api) {
@@ -39,8 +69,23 @@ console.log(result);`);
       const result = await window.api.analyze(code);
       setAnalysisResult(result);
 
+      // Sublinha os erros de análise direto no editor
+      const model = editorRef.current?.getModel();
+      const monaco = monacoRef.current;
+      if (model && monaco) {
+        const errors: ParseError[] = result.errors ?? [];
+        monaco.editor.setModelMarkers(model, 'rustots', errors.map((error) => ({
+          severity: monaco.MarkerSeverity.Error,
+          message: `${error.code}: ${error.message}`,
+          startLineNumber: error.span.start_line,
+          startColumn: error.span.start_column,
+          endLineNumber: error.span.end_line,
+          endColumn: error.span.end_column,
+        })));
+      }
+
       // Mostrar toast se houver erro e estiver na aba de Tokens
-      if (result.error && activeTab === 'tokens') {
+      if (result.errors?.length && activeTab === 'tokens') {
         setShowErrorToast(true);
         setTimeout(() => setShowErrorToast(false), 4000);
       }
@@ -183,7 +228,9 @@ console.log(result);`);
                 }
               });
             }}
-            onMount={(_editor, monaco) => {
+            onMount={(editor, monaco) => {
+              editorRef.current = editor;
+              monacoRef.current = monaco;
               monaco.editor.setTheme('rustots-dark');
             }}
             options={{
@@ -236,14 +283,14 @@ console.log(result);`);
             {activeTab === 'tokens' ? (
               <TokensView tokens={analysisResult?.tokens} />
             ) : (
-              <ASTView ast={analysisResult?.ast} error={analysisResult?.error} />
+              <ASTView ast={analysisResult?.ast} errors={analysisResult?.errors} onHighlight={highlightSpan} />
             )}
           </div>
         </div>
       </div>
 
       {/* Toast de Erro */}
-      {showErrorToast && analysisResult?.error && (
+      {showErrorToast && (analysisResult?.errors.length ?? 0) > 0 && (
         <div className="fixed bottom-6 right-6 bg-red-950/90 border border-red-800 rounded-lg shadow-2xl p-4 max-w-md animate-slide-up backdrop-blur-sm">
           <div className="flex items-start gap-3">
             <div className="flex-shrink-0 w-5 h-5 rounded-full bg-red-500/20 flex items-center justify-center mt-0.5">
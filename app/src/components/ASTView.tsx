@@ -2,13 +2,31 @@ import { ScrollArea } from "./ui/scroll-area";
 import { AlertCircle, ChevronRight, ChevronDown } from "lucide-react";
 import { useState } from "react";
 
+// Trecho do código ocupado por um nó (linhas e colunas a partir de 1, fim exclusivo)
+export interface Span {
+    start: number;
+    end: number;
+    start_line: number;
+    start_column: number;
+    end_line: number;
+    end_column: number;
+}
+
+export interface ParseError {
+    code: string;
+    message: string;
+    span: Span;
+    expected: string[];
+}
+
 interface ASTNodeProps {
     node: any;
     depth?: number;
     name?: string;
+    onHighlight?: (span: Span | null) => void;
 }
 
-function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
+function ASTNodeComponent({ node, depth = 0, name, onHighlight }: ASTNodeProps) {
     const [isExpanded, setIsExpanded] = useState(depth < 3);
 
     if (!node) {
@@ -30,7 +48,7 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
         const entries: [string, any][] = [];
 
         for (const [key, value] of Object.entries(obj)) {
-            if (key === 'type') continue;
+            if (key === 'type' || key === 'span') continue;
 
             if (value && (typeof value === 'object' || Array.isArray(value))) {
                 entries.push([key, value]);
@@ -52,11 +70,78 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
             BinaryExpression: "Expressão Binária",
             Identifier: "Identificador",
             Literal: "Literal",
+            RegExpLiteral: "Expressão Regular",
+            TemplateLiteral: "Template",
+            TaggedTemplateExpression: "Template com Tag",
             ReturnStatement: "Declaração de Retorno",
             ExpressionStatement: "Declaração de Expressão",
             BlockStatement: "Bloco de Declarações",
             VariableDeclarator: "Declarador de Variável",
             MemberExpression: "Expressão de Membro",
+            ChainExpression: "Encadeamento Opcional (?.)",
+            NewExpression: "Instanciação (new)",
+            ThisExpression: "this",
+            Super: "super",
+            MetaProperty: "Metapropriedade",
+            ImportExpression: "Importação Dinâmica",
+            IfStatement: "Condicional (if)",
+            ForStatement: "Laço for",
+            ForInStatement: "Laço for-in",
+            ForOfStatement: "Laço for-of",
+            WhileStatement: "Laço while",
+            DoWhileStatement: "Laço do-while",
+            SwitchStatement: "Switch",
+            TryStatement: "Bloco try",
+            ThrowStatement: "Lançamento (throw)",
+            FunctionExpression: "Expressão de Função",
+            ArrowFunctionExpression: "Arrow Function",
+            AwaitExpression: "Expressão await",
+            YieldExpression: "Expressão yield",
+            AssignmentExpression: "Atribuição",
+            UpdateExpression: "Incremento/Decremento",
+            ConditionalExpression: "Expressão Condicional (?:)",
+            LogicalExpression: "Expressão Lógica",
+            SequenceExpression: "Sequência (,)",
+            UnaryExpression: "Expressão Unária",
+            ObjectExpression: "Objeto Literal",
+            ArrayExpression: "Array Literal",
+            SpreadElement: "Espalhamento (...)",
+            Property: "Propriedade",
+            ObjectPattern: "Desestruturação de Objeto",
+            ArrayPattern: "Desestruturação de Array",
+            RestElement: "Resto (...)",
+            AssignmentPattern: "Valor Padrão",
+            ClassDeclaration: "Declaração de Classe",
+            ClassExpression: "Expressão de Classe",
+            PropertyDefinition: "Propriedade",
+            MethodDefinition: "Método",
+            StaticBlock: "Bloco Estático",
+            TSAsExpression: "Conversão (as)",
+            TSSatisfiesExpression: "Verificação (satisfies)",
+            TSNonNullExpression: "Asserção Não Nula (!)",
+            TSTypeAssertion: "Conversão (<T>)",
+            TSKeywordType: "Tipo Primitivo",
+            TSConstType: "Conversão Constante (const)",
+            TSTypeReference: "Referência de Tipo",
+            TSImportType: "Tipo Importado",
+            TSUnionType: "União de Tipos",
+            TSIntersectionType: "Interseção de Tipos",
+            TSArrayType: "Tipo Array",
+            TSTupleType: "Tupla",
+            TSFunctionType: "Tipo Função",
+            TSTypeLiteral: "Tipo Objeto",
+            TSLiteralType: "Tipo Literal",
+            TSConditionalType: "Tipo Condicional",
+            TSMappedType: "Tipo Mapeado",
+            TSInterfaceDeclaration: "Declaração de Interface",
+            TSTypeAliasDeclaration: "Alias de Tipo",
+            TSEnumDeclaration: "Declaração de Enum",
+            ImportDeclaration: "Importação",
+            ExportNamedDeclaration: "Exportação Nomeada",
+            ExportDefaultDeclaration: "Exportação Padrão",
+            ExportAllDeclaration: "Reexportação (*)",
+            TSExportAssignment: "Exportação (export =)",
+            TSImportEqualsDeclaration: "Importação (import =)",
             Object: "Objeto",
         };
         return translations[type] || type;
@@ -73,6 +158,7 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
             expression: "expressão",
             id: "id",
             params: "parâmetros",
+            decorators: "decoradores",
             return_type: "tipo_retorno",
             declarations: "declarações",
             init: "inicialização",
@@ -84,6 +170,54 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
             name: "nome",
             value: "valor",
             raw: "bruto",
+            test: "condição",
+            consequent: "então",
+            alternate: "senão",
+            update: "atualização",
+            cases: "casos",
+            handler: "catch",
+            finalizer: "finally",
+            label: "rótulo",
+            super_class: "superclasse",
+            implements: "implementa",
+            key: "chave",
+            type_annotation: "anotação_tipo",
+            pattern: "padrão",
+            flags: "flags",
+            quasis: "textos",
+            expressions: "expressões",
+            tag: "tag",
+            quasi: "template",
+            tail: "final",
+            type_name: "nome_tipo",
+            type_arguments: "argumentos_tipo",
+            types: "tipos",
+            element_type: "tipo_elemento",
+            element_types: "tipos_elementos",
+            members: "membros",
+            check_type: "tipo_verificado",
+            extends_type: "tipo_extends",
+            true_type: "tipo_verdadeiro",
+            false_type: "tipo_falso",
+            type_parameters: "parâmetros_tipo",
+            constraint: "restrição",
+            default: "padrão",
+            extends: "estende",
+            initializer: "inicializador",
+            specifiers: "especificadores",
+            source: "origem",
+            imported: "importado",
+            exported: "exportado",
+            local: "local",
+            attributes: "atributos",
+            declaration: "declaração",
+            module_reference: "referência_módulo",
+            delegate: "delegado",
+            properties: "propriedades",
+            elements: "elementos",
+            shorthand: "abreviado",
+            method: "método",
+            prefix: "prefixo",
         };
         return translations[name] || name;
     };
@@ -118,6 +252,7 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
     };
 
     const simpleProps = getSimpleProps();
+    const span: Span | undefined = node.span;
 
     return (
         <div className="text-sm">
@@ -127,6 +262,8 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
                     : "hover:bg-neutral-950/50"
                     }`}
                 onClick={() => hasChildren && setIsExpanded(!isExpanded)}
+                onMouseEnter={() => span && onHighlight?.(span)}
+                onMouseLeave={() => span && onHighlight?.(null)}
             >
                 {hasChildren && (
                     <button className="flex-shrink-0 mt-0.5 text-gray-500 hover:text-gray-300 transition-colors">
@@ -145,6 +282,11 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
                         <span className={`text-xs font-medium px-1.5 py-0.5 rounded border ${colorClass}`}>
                             {translateNodeType(nodeType)}
                         </span>
+                        {span && (
+                            <span className="text-[10px] text-gray-600 font-mono">
+                                {span.start_line}:{span.start_column}–{span.end_line}:{span.end_column}
+                            </span>
+                        )}
                     </div>
                     {Object.keys(simpleProps).length > 0 && (
                         <div className="mt-1 text-xs text-gray-500 flex flex-wrap gap-x-2 gap-y-0.5">
@@ -170,6 +312,7 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
                                     node={item}
                                     depth={depth + 1}
                                     name={`${key}[${index}]`}
+                                    onHighlight={onHighlight}
                                 />
                             ));
                         } else {
@@ -179,6 +322,7 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
                                     node={value}
                                     depth={depth + 1}
                                     name={key}
+                                    onHighlight={onHighlight}
                                 />
                             );
                         }
@@ -189,33 +333,41 @@ function ASTNodeComponent({ node, depth = 0, name }: ASTNodeProps) {
     );
 }
 
+function ErrorItem({ error, onHighlight }: { error: ParseError; onHighlight?: (span: Span | null) => void }) {
+    return (
+        <div
+            className="text-xs text-gray-400 bg-red-950/30 border border-red-900/50 rounded p-3 font-mono text-left break-words space-y-1 cursor-pointer"
+            onMouseEnter={() => onHighlight?.(error.span)}
+            onMouseLeave={() => onHighlight?.(null)}
+        >
+            <p>
+                <span className="text-red-400">{error.code}</span>
+                <span className="text-gray-500"> em {error.span.start_line}:{error.span.start_column}</span>
+            </p>
+            <p className="text-gray-300">{error.message}</p>
+            {error.expected.length > 0 && (
+                <p className="text-gray-500">
+                    Esperado: {error.expected.join(', ')}
+                </p>
+            )}
+        </div>
+    );
+}
+
 interface ASTViewProps {
     ast: any;
-    error?: string;
+    errors?: ParseError[];
+    onHighlight?: (span: Span | null) => void;
 }
 
-export function ASTView({ ast, error }: ASTViewProps) {
+export function ASTView({ ast, errors = [], onHighlight }: ASTViewProps) {
     if (!ast) {
         return (
             <div className="h-full flex items-center justify-center text-gray-400 p-8">
                 <div className="text-center space-y-3">
                     <AlertCircle className="w-10 h-10 mx-auto opacity-40" />
-                    {error ? (
-                        <>
-                            <p className="text-sm font-medium text-red-400">Erro na Análise</p>
-                            <div className="max-w-md mx-auto">
-                                <p className="text-xs text-gray-400 bg-red-950/30 border border-red-900/50 rounded p-3 font-mono text-left break-words">
-                                    {error}
-                                </p>
-                            </div>
-                            <p className="text-xs text-gray-500 mt-2">Verifique a sintaxe do seu código (ponto e vírgula, parênteses, etc.)</p>
-                        </>
-                    ) : (
-                        <>
-                            <p className="text-sm font-medium text-gray-300">Nenhuma AST para exibir</p>
-                            <p className="text-xs text-gray-500">Clique em "Analisar" para ver a árvore sintática</p>
-                        </>
-                    )}
+                    <p className="text-sm font-medium text-gray-300">Nenhuma AST para exibir</p>
+                    <p className="text-xs text-gray-500">Clique em "Analisar" para ver a árvore sintática</p>
                 </div>
             </div>
         );
@@ -224,16 +376,27 @@ export function ASTView({ ast, error }: ASTViewProps) {
     return (
         <ScrollArea className="h-full">
             <div className="p-4">
+                {errors.length > 0 && (
+                    <div className="mb-3 space-y-2">
+                        <p className="text-xs font-medium text-red-400">
+                            {errors.length === 1 ? '1 erro de sintaxe' : `${errors.length} erros de sintaxe`}
+                            <span className="text-gray-500"> — os trechos com erro aparecem como nós Error</span>
+                        </p>
+                        {errors.map((error, index) => (
+                            <ErrorItem key={index} error={error} onHighlight={onHighlight} />
+                        ))}
+                    </div>
+                )}
                 <div className="mb-3 p-3 bg-neutral-950 rounded border border-neutral-800">
                     <h3 className="text-xs font-semibold text-gray-300 mb-1">
                         Árvore Sintática Abstrata
                     </h3>
                     <p className="text-[11px] text-gray-500">
-                        Clique nos nós para expandir/recolher
+                        Clique nos nós para expandir/recolher; passe o mouse para destacar o trecho no editor
                     </p>
                 </div>
                 <div className="border border-neutral-800 rounded p-3 bg-black">
-                    <ASTNodeComponent node={ast} />
+                    <ASTNodeComponent node={ast} onHighlight={onHighlight} />
                 </div>
             </div>
         </ScrollArea>
@@ -5,12 +5,15 @@ import { AlertCircle } from "lucide-react";
 
 export interface Token {
     type: string;
+    kind: string;
     value: string;
     position: {
         start: number;
         end: number;
         line: number;
         column: number;
+        end_line: number;
+        end_column: number;
     };
     malformed?: string;
 }
@@ -24,6 +27,8 @@ const translateTokenType = (type: string): string => {
         keyword: "Palavra-chave",
         identifier: "Identificador",
         literal: "Literal",
+        regexp: "Expressão Regular",
+        template: "Template",
         operator: "Operador",
         punctuation: "Pontuação",
         comment: "Comentário",
@@ -37,6 +42,8 @@ const tokenColorMap: Record<string, string> = {
     keyword: "bg-blue-500/15 text-blue-300 border-blue-500/30",
     identifier: "bg-purple-500/15 text-purple-300 border-purple-500/30",
     literal: "bg-green-500/15 text-green-300 border-green-500/30",
+    regexp: "bg-lime-500/15 text-lime-300 border-lime-500/30",
+    template: "bg-emerald-500/15 text-emerald-300 border-emerald-500/30",
     operator: "bg-orange-500/15 text-orange-300 border-orange-500/30",
     punctuation: "bg-pink-500/15 text-pink-300 border-pink-500/30",
     comment: "bg-slate-500/15 text-slate-300 border-slate-500/30",
@@ -0,0 +1,110 @@
+//! Mede o tempo de análise de uma entrada grande, com muitas expressões.
+//!
+//! Uso: `cargo bench --bench parser [-- <repetições do trecho>] [--save <arquivo>] [--baseline <arquivo>]`
+//!
+//! `--save` grava as medianas em um arquivo; `--baseline` compara a medição atual
+//! com um arquivo gravado antes, em outra versão do código.
+
+use rustots::lexer::Lexer;
+use rustots::parser::Parser;
+use std::fs;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: usize = 20;
+
+// Um trecho que passa por todos os níveis de precedência
+const SNIPPET: &str = r#"
+function calc(a: number, b: number, c?: number): number {
+    let total = a * b + c / 2 - (a % 3) ** 2;
+    total += a << 2 | b >> 1 & ~c ^ a >>> 3;
+    const ok = a === b || b !== c && a <= b ? total : -total;
+    const fallback = c ?? a ?? b;
+    for (let i = 0; i < total && i in xs; i++) {
+        total = total * 2 + xs[i].value - obj.fn(a, b, ...rest) / (i + 1);
+    }
+    return ok instanceof Number ? fallback : (a, b, typeof c === "number" ? c : void 0);
+}
+const results = items.map((item) => item.price * item.quantity + tax(item) - discount(item, 0.1));
+const { x, y = 1, ...others } = point;
+let flags = !enabled && (count > 10 || count < -10) ? mask & 0xff : mask | 0x100;
+"#;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let option = |name: &str| args.iter().position(|arg| arg == name).and_then(|i| args.get(i + 1));
+    let repetitions = args.iter().find_map(|arg| arg.parse::<usize>().ok()).unwrap_or(2000);
+    let baseline = option("--baseline").map(|path| {
+        let contents = fs::read_to_string(path).unwrap_or_else(|e| panic!("não foi possível ler '{}': {}", path, e));
+        // Só faz sentido comparar medições da mesma entrada
+        assert!(
+            contents.lines().all(|line| line.split_whitespace().nth(1) == Some(&repetitions.to_string())),
+            "'{}' foi gravado com outro número de repetições",
+            path
+        );
+        contents
+    });
+    let input = SNIPPET.repeat(repetitions);
+    let megabytes = input.len() as f64 / (1024.0 * 1024.0);
+
+    let mut lex_times = Vec::with_capacity(ITERATIONS);
+    let mut parse_times = Vec::with_capacity(ITERATIONS);
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        let tokens = Lexer::new(&input).tokenize();
+        lex_times.push(start.elapsed());
+
+        let start = Instant::now();
+        let (program, errors) = Parser::new(tokens).parse();
+        assert!(errors.is_empty(), "a entrada do benchmark deve ser válida");
+        parse_times.push(start.elapsed());
+        std::hint::black_box(program);
+    }
+
+    println!("entrada: {:.2} MB ({} repetições do trecho)", megabytes, repetitions);
+    let medians = [
+        report("lexer", &mut lex_times, megabytes, baseline.as_deref()),
+        report("parser", &mut parse_times, megabytes, baseline.as_deref()),
+    ];
+
+    if let Some(path) = option("--save") {
+        // Uma linha por etapa: nome, repetições e mediana em nanossegundos
+        let contents: String = ["lexer", "parser"]
+            .iter()
+            .zip(medians)
+            .map(|(name, median)| format!("{} {} {}\n", name, repetitions, median.as_nanos()))
+            .collect();
+        fs::write(path, contents).unwrap_or_else(|e| panic!("não foi possível gravar '{}': {}", path, e));
+    }
+}
+
+fn report(name: &str, times: &mut [Duration], megabytes: f64, baseline: Option<&str>) -> Duration {
+    times.sort();
+    let median = times[times.len() / 2];
+    print!(
+        "{:<8} mínimo {:>8.2?}  mediana {:>8.2?}  {:>7.1} MB/s",
+        name,
+        times[0],
+        median,
+        megabytes / median.as_secs_f64()
+    );
+    match baseline.and_then(|contents| baseline_median(contents, name)) {
+        Some(before) => {
+            let change = (median.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+            println!("  base {:>8.2?} ({:+.1}%)", before, change);
+        }
+        None => println!(),
+    }
+    median
+}
+
+/// Mediana de `name` em um arquivo gravado com `--save`.
+fn baseline_median(contents: &str, name: &str) -> Option<Duration> {
+    contents.lines().find_map(|line| {
+        let mut fields = line.split_whitespace();
+        if fields.next()? != name {
+            return None;
+        }
+        let nanos = fields.nth(1)?.parse().ok()?;
+        Some(Duration::from_nanos(nanos))
+    })
+}
@@ -2,8 +2,11 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Token {
+    /// Categoria geral, usada pela interface para colorir os tokens.
     #[serde(rename = "type")]
     pub token_type: TokenType,
+    /// Tipo detalhado, usado pelo parser.
+    pub kind: TokenKind,
     pub value: String,
     pub position: Position,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -17,6 +20,8 @@ pub enum TokenType {
     Keyword,
     Operator,
     Literal,
+    RegExp,
+    Template,
     Punctuation,
     Comment,
     Whitespace,
@@ -24,12 +29,344 @@ pub enum TokenType {
     Unknown,
 }
 
+/// Define `TokenKind` a partir das tabelas de palavras-chave e pontuadores,
+/// gerando junto as conversões de/para texto.
+macro_rules! token_kinds {
+    (
+        keywords { $($keyword:ident => $keyword_text:literal,)* }
+        punctuators { $($punct:ident => $punct_text:literal,)* }
+    ) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub enum TokenKind {
+            Identifier,
+            /// `#nome` de um membro privado de classe
+            PrivateName,
+            NumericLiteral,
+            BigIntLiteral,
+            StringLiteral,
+            RegExpLiteral,
+            /// `` `texto` ``
+            NoSubstitutionTemplate,
+            /// `` `texto${ ``
+            TemplateHead,
+            /// `}texto${`
+            TemplateMiddle,
+            /// `` }texto` ``
+            TemplateTail,
+            Comment,
+            Whitespace,
+            Newline,
+            /// Caractere que não pertence a nenhum token válido.
+            Invalid,
+            $($keyword,)*
+            $($punct,)*
+        }
+
+        impl TokenKind {
+            /// Todos os tipos, na ordem da declaração (`TokenKind::ALL[k as usize] == k`).
+            pub const ALL: &'static [TokenKind] = &[
+                TokenKind::Identifier,
+                TokenKind::PrivateName,
+                TokenKind::NumericLiteral,
+                TokenKind::BigIntLiteral,
+                TokenKind::StringLiteral,
+                TokenKind::RegExpLiteral,
+                TokenKind::NoSubstitutionTemplate,
+                TokenKind::TemplateHead,
+                TokenKind::TemplateMiddle,
+                TokenKind::TemplateTail,
+                TokenKind::Comment,
+                TokenKind::Whitespace,
+                TokenKind::Newline,
+                TokenKind::Invalid,
+                $(TokenKind::$keyword,)*
+                $(TokenKind::$punct,)*
+            ];
+
+            pub fn from_keyword(text: &str) -> Option<TokenKind> {
+                match text {
+                    $($keyword_text => Some(TokenKind::$keyword),)*
+                    _ => None,
+                }
+            }
+
+            pub fn from_punctuator(text: &str) -> Option<TokenKind> {
+                match text {
+                    $($punct_text => Some(TokenKind::$punct),)*
+                    _ => None,
+                }
+            }
+
+            pub fn is_keyword(self) -> bool {
+                matches!(self, $(TokenKind::$keyword)|*)
+            }
+
+            /// Texto do token (para palavras-chave e pontuadores) ou uma descrição
+            /// legível, usada nas mensagens de erro.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    TokenKind::Identifier => "identificador",
+                    TokenKind::PrivateName => "nome privado",
+                    TokenKind::NumericLiteral => "literal numérico",
+                    TokenKind::BigIntLiteral => "literal BigInt",
+                    TokenKind::StringLiteral => "literal de string",
+                    TokenKind::RegExpLiteral => "expressão regular",
+                    TokenKind::NoSubstitutionTemplate
+                    | TokenKind::TemplateHead
+                    | TokenKind::TemplateMiddle
+                    | TokenKind::TemplateTail => "template",
+                    TokenKind::Comment => "comentário",
+                    TokenKind::Whitespace => "espaço",
+                    TokenKind::Newline => "quebra de linha",
+                    TokenKind::Invalid => "caractere inválido",
+                    $(TokenKind::$keyword => $keyword_text,)*
+                    $(TokenKind::$punct => $punct_text,)*
+                }
+            }
+        }
+    };
+}
+
+token_kinds! {
+    keywords {
+        Abstract => "abstract",
+        Any => "any",
+        As => "as",
+        Asserts => "asserts",
+        Bigint => "bigint",
+        Boolean => "boolean",
+        Break => "break",
+        Case => "case",
+        Catch => "catch",
+        Class => "class",
+        Const => "const",
+        Continue => "continue",
+        Debugger => "debugger",
+        Declare => "declare",
+        Default => "default",
+        Delete => "delete",
+        Do => "do",
+        Else => "else",
+        Enum => "enum",
+        Export => "export",
+        Extends => "extends",
+        False => "false",
+        Finally => "finally",
+        For => "for",
+        From => "from",
+        Function => "function",
+        Get => "get",
+        If => "if",
+        Implements => "implements",
+        Import => "import",
+        In => "in",
+        Infer => "infer",
+        Instanceof => "instanceof",
+        Interface => "interface",
+        Is => "is",
+        Keyof => "keyof",
+        Let => "let",
+        Module => "module",
+        Namespace => "namespace",
+        Never => "never",
+        New => "new",
+        Null => "null",
+        Number => "number",
+        Object => "object",
+        Of => "of",
+        Package => "package",
+        Private => "private",
+        Protected => "protected",
+        Public => "public",
+        Readonly => "readonly",
+        Require => "require",
+        Return => "return",
+        Set => "set",
+        Static => "static",
+        String => "string",
+        Super => "super",
+        Switch => "switch",
+        Symbol => "symbol",
+        This => "this",
+        Throw => "throw",
+        True => "true",
+        Try => "try",
+        Type => "type",
+        Typeof => "typeof",
+        Undefined => "undefined",
+        Unique => "unique",
+        Unknown => "unknown",
+        Var => "var",
+        Void => "void",
+        While => "while",
+        With => "with",
+        Yield => "yield",
+    }
+    punctuators {
+        LBrace => "{",
+        RBrace => "}",
+        LParen => "(",
+        RParen => ")",
+        LBracket => "[",
+        RBracket => "]",
+        Semicolon => ";",
+        Comma => ",",
+        Dot => ".",
+        Ellipsis => "...",
+        QuestionDot => "?.",
+        At => "@",
+        Lt => "<",
+        Gt => ">",
+        LtEq => "<=",
+        GtEq => ">=",
+        EqEq => "==",
+        NotEq => "!=",
+        EqEqEq => "===",
+        NotEqEq => "!==",
+        Plus => "+",
+        Minus => "-",
+        Star => "*",
+        Slash => "/",
+        Percent => "%",
+        StarStar => "**",
+        PlusPlus => "++",
+        MinusMinus => "--",
+        ShiftLeft => "<<",
+        ShiftRight => ">>",
+        UnsignedShiftRight => ">>>",
+        Amp => "&",
+        Pipe => "|",
+        Caret => "^",
+        Bang => "!",
+        Tilde => "~",
+        AmpAmp => "&&",
+        PipePipe => "||",
+        QuestionQuestion => "??",
+        Question => "?",
+        Colon => ":",
+        Eq => "=",
+        PlusEq => "+=",
+        MinusEq => "-=",
+        StarEq => "*=",
+        SlashEq => "/=",
+        PercentEq => "%=",
+        StarStarEq => "**=",
+        ShiftLeftEq => "<<=",
+        ShiftRightEq => ">>=",
+        UnsignedShiftRightEq => ">>>=",
+        AmpEq => "&=",
+        PipeEq => "|=",
+        CaretEq => "^=",
+        AmpAmpEq => "&&=",
+        PipePipeEq => "||=",
+        QuestionQuestionEq => "??=",
+        Arrow => "=>",
+    }
+}
+
+impl TokenKind {
+    /// Categoria geral do token, mantida no JSON para a interface.
+    pub fn category(self) -> TokenType {
+        match self {
+            TokenKind::Identifier | TokenKind::PrivateName => TokenType::Identifier,
+            TokenKind::NumericLiteral | TokenKind::BigIntLiteral | TokenKind::StringLiteral => TokenType::Literal,
+            TokenKind::RegExpLiteral => TokenType::RegExp,
+            TokenKind::NoSubstitutionTemplate
+            | TokenKind::TemplateHead
+            | TokenKind::TemplateMiddle
+            | TokenKind::TemplateTail => TokenType::Template,
+            TokenKind::Comment => TokenType::Comment,
+            TokenKind::Whitespace => TokenType::Whitespace,
+            TokenKind::Newline => TokenType::Newline,
+            TokenKind::Invalid => TokenType::Unknown,
+            TokenKind::LBrace
+            | TokenKind::RBrace
+            | TokenKind::LParen
+            | TokenKind::RParen
+            | TokenKind::LBracket
+            | TokenKind::RBracket
+            | TokenKind::Semicolon
+            | TokenKind::Comma
+            | TokenKind::Dot
+            | TokenKind::Ellipsis
+            | TokenKind::QuestionDot
+            | TokenKind::At => TokenType::Punctuation,
+            kind if kind.is_keyword() => TokenType::Keyword,
+            _ => TokenType::Operator,
+        }
+    }
+
+    /// Palavras-chave do TypeScript (e `of`, `get`, `set`, `from`, `as`) que só são
+    /// especiais em certas posições; no resto do código valem como identificadores.
+    pub fn is_contextual_keyword(self) -> bool {
+        matches!(
+            self,
+            TokenKind::Abstract
+                | TokenKind::Any
+                | TokenKind::As
+                | TokenKind::Asserts
+                | TokenKind::Bigint
+                | TokenKind::Boolean
+                | TokenKind::Declare
+                | TokenKind::From
+                | TokenKind::Get
+                | TokenKind::Infer
+                | TokenKind::Is
+                | TokenKind::Keyof
+                | TokenKind::Module
+                | TokenKind::Namespace
+                | TokenKind::Never
+                | TokenKind::Number
+                | TokenKind::Object
+                | TokenKind::Of
+                | TokenKind::Readonly
+                | TokenKind::Require
+                | TokenKind::Set
+                | TokenKind::String
+                | TokenKind::Symbol
+                | TokenKind::Type
+                | TokenKind::Undefined
+                | TokenKind::Unique
+                | TokenKind::Unknown
+        )
+    }
+
+    /// Palavras reservadas só no modo estrito, que vale em todo módulo.
+    pub fn is_strict_mode_reserved(self) -> bool {
+        matches!(
+            self,
+            TokenKind::Implements
+                | TokenKind::Interface
+                | TokenKind::Let
+                | TokenKind::Package
+                | TokenKind::Private
+                | TokenKind::Protected
+                | TokenKind::Public
+                | TokenKind::Static
+                | TokenKind::Yield
+        )
+    }
+
+    /// Se o token pode ser usado como nome de variável, função ou rótulo.
+    pub fn can_be_identifier(self) -> bool {
+        self == TokenKind::Identifier || self.is_contextual_keyword()
+    }
+
+    /// Espaços, quebras de linha e comentários não afetam a sintaxe.
+    pub fn is_trivia(self) -> bool {
+        matches!(self, TokenKind::Whitespace | TokenKind::Newline | TokenKind::Comment)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub start: usize,
     pub end: usize,
     pub line: usize,
     pub column: usize,
+    // Linha e coluna logo depois do último caractere do token
+    pub end_line: usize,
+    pub end_column: usize,
 }
 
 pub struct Lexer<'a> {
@@ -37,6 +374,17 @@ pub struct Lexer<'a> {
     position: usize,
     line: usize,
     column: usize,
+    // Decide se um `/` inicia uma expressão regular ou é o operador de divisão,
+    // com base no último token significativo (ignorando espaços e comentários).
+    regex_allowed: bool,
+    // Os dois últimos tokens significativos, do mais recente para o mais antigo
+    previous: [Option<TokenKind>; 2],
+    // Pilha de parênteses abertos; `true` marca os que abriram o cabeçalho de um
+    // `if`, `while`, `for` ou `with`, depois dos quais vem uma instrução, não um operador.
+    paren_stack: Vec<bool>,
+    // Pilha de chaves abertas; `true` marca as que abriram uma substituição `${`
+    // de template, para sabermos quando um `}` retoma o texto do template.
+    brace_stack: Vec<bool>,
 }
 
 impl<'a> Lexer<'a> {
@@ -46,6 +394,10 @@ impl<'a> Lexer<'a> {
             position: 0,
             line: 1,
             column: 1,
+            regex_allowed: true,
+            previous: [None; 2],
+            paren_stack: Vec::new(),
+            brace_stack: Vec::new(),
         }
     }
 
@@ -54,6 +406,10 @@ impl<'a> Lexer<'a> {
 
         while self.position < self.input.len() {
             if let Some(token) = self.next_token() {
+                if !token.kind.is_trivia() {
+                    self.regex_allowed = self.regex_allowed_after(&token);
+                    self.previous = [Some(token.kind), self.previous[0]];
+                }
                 tokens.push(token);
             }
         }
@@ -66,165 +422,76 @@ impl<'a> Lexer<'a> {
         let start_line = self.line;
         let start_column = self.column;
 
-        if let Some(ch) = self.current_char() {
-            match ch {
-                ' ' | '\t' => {
-                    self.consume_whitespace();
-                    Some(Token {
-                        token_type: TokenType::Whitespace,
-                        value: self.input[start_pos..self.position].to_string(),
-                        position: Position {
-                            start: start_pos,
-                            end: self.position,
-                            line: start_line,
-                            column: start_column,
-                        },
-                        malformed: None,
-                    })
-                }
-                '\n' | '\r' => {
-                    self.advance();
-                    if ch == '\r' && self.current_char() == Some('\n') {
-                        self.advance();
-                    }
-                    self.line += 1;
-                    self.column = 1;
-                    Some(Token {
-                        token_type: TokenType::Newline,
-                        value: self.input[start_pos..self.position].to_string(),
-                        position: Position {
-                            start: start_pos,
-                            end: self.position,
-                            line: start_line,
-                            column: start_column,
-                        },
-                        malformed: None,
-                    })
-                }
-                _ if ch.is_alphabetic() || ch == '_' || ch == '$' => {
-                    let value = self.consume_identifier();
-                    let token_type = if self.is_keyword(&value) {
-                        TokenType::Keyword
-                    } else {
-                        TokenType::Identifier
-                    };
-                    Some(Token {
-                        token_type,
-                        value,
-                        position: Position {
-                            start: start_pos,
-                            end: self.position,
-                            line: start_line,
-                            column: start_column,
-                        },
-                        malformed: None,
-                    })
-                }
-                _ if ch.is_numeric() => {
-                    let (value, malformed) = self.consume_number();
-                    Some(Token {
-                        token_type: TokenType::Literal,
-                        value,
-                        position: Position {
-                            start: start_pos,
-                            end: self.position,
-                            line: start_line,
-                            column: start_column,
-                        },
-                        malformed,
-                    })
-                }
-                '"' | '\'' | '`' => {
-                    let (value, malformed) = self.consume_string(ch);
-                    Some(Token {
-                        token_type: TokenType::Literal,
-                        value,
-                        position: Position {
-                            start: start_pos,
-                            end: self.position,
-                            line: start_line,
-                            column: start_column,
-                        },
-                        malformed,
-                    })
-                }
-                '/' => {
-                    if self.peek_char() == Some('/') || self.peek_char() == Some('*') {
-                        let (value, malformed) = self.consume_comment();
-                        Some(Token {
-                            token_type: TokenType::Comment,
-                            value,
-                            position: Position {
-                                start: start_pos,
-                                end: self.position,
-                                line: start_line,
-                                column: start_column,
-                            },
-                            malformed,
-                        })
-                    } else {
-                        self.advance();
-                        Some(Token {
-                            token_type: TokenType::Operator,
-                            value: "/".to_string(),
-                            position: Position {
-                                start: start_pos,
-                                end: self.position,
-                                line: start_line,
-                                column: start_column,
-                            },
-                            malformed: None,
-                        })
-                    }
-                }
-                '+' | '-' | '*' | '=' | '!' | '<' | '>' | '&' | '|' | '^' | '%' | '?' | ':' => {
-                    let value = self.consume_operator();
-                    Some(Token {
-                        token_type: TokenType::Operator,
-                        value,
-                        position: Position {
-                            start: start_pos,
-                            end: self.position,
-                            line: start_line,
-                            column: start_column,
-                        },
-                        malformed: None,
-                    })
-                }
-                '{' | '}' | '(' | ')' | '[' | ']' | ';' | ',' | '.' => {
+        let ch = self.current_char()?;
+        let (kind, malformed) = match ch {
+            ' ' | '\t' => {
+                self.consume_whitespace();
+                (TokenKind::Whitespace, None)
+            }
+            '\n' | '\r' => {
+                self.advance();
+                if ch == '\r' && self.current_char() == Some('\n') {
                     self.advance();
-                    Some(Token {
-                        token_type: TokenType::Punctuation,
-                        value: ch.to_string(),
-                        position: Position {
-                            start: start_pos,
-                            end: self.position,
-                            line: start_line,
-                            column: start_column,
-                        },
-                        malformed: None,
-                    })
                 }
-                _ => {
-                    // Se chegamos aqui, o caractere não bateu com nenhuma regra conhecida.
-                    // Marcamos como erro léxico para o usuário corrigir.
-                    self.advance();
-                    return Some(Token {
-                        token_type: TokenType::Unknown,
-                        value: ch.to_string(),
-                        position: Position {
-                            start: start_pos,
-                            end: self.position,
-                            line: start_line,
-                            column: start_column,
-                        },
-                        malformed: Some(format!("Caractere não reconhecido: '{}'", ch)),
-                    });
+                (TokenKind::Newline, None)
+            }
+            _ if ch.is_alphabetic() || ch == '_' || ch == '$' => {
+                let value = self.consume_identifier();
+                (TokenKind::from_keyword(value).unwrap_or(TokenKind::Identifier), None)
+            }
+            _ if ch.is_ascii_digit() || (ch == '.' && self.peek_char().is_some_and(|c| c.is_ascii_digit())) => {
+                self.consume_number()
+            }
+            '#' if self.peek_char().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$') => {
+                self.advance();
+                self.consume_identifier();
+                (TokenKind::PrivateName, None)
+            }
+            '`' => self.consume_template(),
+            '}' if self.brace_stack.last() == Some(&true) => {
+                // Fim de uma substituição `${ ... }`: o template continua daqui
+                self.brace_stack.pop();
+                self.consume_template()
+            }
+            '"' | '\'' => (TokenKind::StringLiteral, self.consume_string(ch)),
+            '/' if self.peek_char() == Some('/') || self.peek_char() == Some('*') => {
+                (TokenKind::Comment, self.consume_comment())
+            }
+            '/' if self.regex_allowed => (TokenKind::RegExpLiteral, self.consume_regex()),
+            '{' | '}' | '(' | ')' | '[' | ']' | ';' | ',' | '@' => {
+                if ch == '{' {
+                    self.brace_stack.push(false);
+                } else if ch == '}' {
+                    self.brace_stack.pop();
                 }
+                self.advance();
+                (TokenKind::from_punctuator(&self.input[start_pos..self.position]).unwrap(), None)
             }
-        } else {
-            None
-        }
+            '+' | '-' | '*' | '/' | '=' | '!' | '<' | '>' | '&' | '|' | '^' | '%' | '?' | ':' | '~' | '.' => {
+                (self.consume_operator(), None)
+            }
+            _ => {
+                // Se chegamos aqui, o caractere não bateu com nenhuma regra conhecida.
+                // Marcamos como erro léxico para o usuário corrigir.
+                self.advance();
+                (TokenKind::Invalid, Some(format!("Caractere não reconhecido: '{}'", ch)))
+            }
+        };
+
+        Some(Token {
+            token_type: kind.category(),
+            kind,
+            value: self.input[start_pos..self.position].to_string(),
+            position: Position {
+                start: start_pos,
+                end: self.position,
+                line: start_line,
+                column: start_column,
+                end_line: self.line,
+                end_column: self.column,
+            },
+            malformed,
+        })
     }
 
     fn current_char(&self) -> Option<char> {
@@ -238,7 +505,13 @@ impl<'a> Lexer<'a> {
     fn advance(&mut self) {
         if let Some(ch) = self.current_char() {
             self.position += ch.len_utf8();
-            self.column += 1;
+            // `\r\n` conta como uma única quebra de linha
+            if ch == '\n' || (ch == '\r' && self.current_char() != Some('\n')) {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
         }
     }
 
@@ -252,7 +525,7 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn consume_identifier(&mut self) -> String {
+    fn consume_identifier(&mut self) -> &'a str {
         let start = self.position;
         while let Some(ch) = self.current_char() {
             if ch.is_alphanumeric() || ch == '_' || ch == '$' {
@@ -261,36 +534,175 @@ impl<'a> Lexer<'a> {
                 break;
             }
         }
-        self.input[start..self.position].to_string()
+        &self.input[start..self.position]
     }
 
-    fn consume_number(&mut self) -> (String, Option<String>) {
+    fn consume_number(&mut self) -> (TokenKind, Option<String>) {
         let start = self.position;
-        let mut dot_count = 0;
-        
+        let mut error: Option<String> = None;
+        let mut kind = TokenKind::NumericLiteral;
+
+        let first = self.current_char().unwrap();
+        let radix = match (first, self.peek_char()) {
+            ('0', Some('x' | 'X')) => Some((16, "hexadecimal")),
+            ('0', Some('o' | 'O')) => Some((8, "octal")),
+            ('0', Some('b' | 'B')) => Some((2, "binário")),
+            _ => None,
+        };
+
+        if let Some((radix, name)) = radix {
+            // 0x.., 0o.., 0b.. (aceitam separadores e sufixo BigInt)
+            self.advance();
+            self.advance();
+            let digits = self.consume_digits(radix, name, &mut error);
+            if digits == 0 {
+                report(&mut error, format!("Literal {} sem dígitos", name));
+            }
+            if self.current_char() == Some('n') {
+                self.advance();
+                kind = TokenKind::BigIntLiteral;
+            }
+        } else if first == '0' && self.peek_char().is_some_and(|c| c.is_ascii_digit() || c == '_') {
+            // Zero à esquerda: octal legado (0755) ou decimal com zero à esquerda (089)
+            self.advance();
+            let digits_start = self.position;
+            while let Some(ch) = self.current_char() {
+                if ch.is_ascii_digit() {
+                    self.advance();
+                } else if ch == '_' {
+                    report(&mut error, "Separador numérico não é permitido após zero à esquerda".to_string());
+                    self.advance();
+                } else {
+                    break;
+                }
+            }
+            let digits = &self.input[digits_start..self.position];
+            let legacy_octal = digits.chars().all(|c| matches!(c, '0'..='7' | '_'));
+
+            if !legacy_octal {
+                self.consume_decimal_tail(&mut error, false);
+            }
+            if self.current_char() == Some('n') {
+                self.advance();
+                kind = TokenKind::BigIntLiteral;
+                report(&mut error, "BigInt não pode ter zero à esquerda".to_string());
+            } else if legacy_octal {
+                report(
+                    &mut error,
+                    format!("Literal octal legado não é permitido; use '0o{}'", digits.replace('_', "")),
+                );
+            } else {
+                report(&mut error, "Decimal com zero à esquerda não é permitido".to_string());
+            }
+        } else {
+            let has_integer = first != '.';
+            if has_integer {
+                self.consume_digits(10, "decimal", &mut error);
+            }
+            let is_integer = self.consume_decimal_tail(&mut error, !has_integer);
+            if self.current_char() == Some('n') {
+                self.advance();
+                kind = TokenKind::BigIntLiteral;
+                if !is_integer {
+                    report(&mut error, "BigInt precisa ser um número inteiro".to_string());
+                }
+            }
+        }
+
+        // Pontos extras seguidos de dígitos (ex: 1.2.3) são absorvidos no mesmo token
+        // para que o erro seja mostrado em um lugar só.
+        let mut dot_count = self.input[start..self.position].matches('.').count();
+        while self.current_char() == Some('.') && self.peek_char().is_some_and(|c| c.is_ascii_digit()) {
+            dot_count += 1;
+            self.advance();
+            while self.current_char().is_some_and(|c| c.is_ascii_digit() || c == '_') {
+                self.advance();
+            }
+        }
+        if dot_count > 1 {
+            error = Some(format!("Número com múltiplos pontos decimais ({})", dot_count));
+        }
+
+        // Um identificador não pode começar colado em um número (ex: 3in, 10px)
+        if self.current_char().is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$') {
+            self.consume_identifier();
+            report(
+                &mut error,
+                "Identificador ou palavra-chave não pode vir imediatamente após um literal numérico".to_string(),
+            );
+        }
+
+        (kind, error)
+    }
+
+    /// Consome dígitos da base informada, validando os separadores `_`.
+    /// Retorna quantos dígitos válidos foram lidos.
+    fn consume_digits(&mut self, radix: u32, name: &str, error: &mut Option<String>) -> usize {
+        let mut count = 0;
+        let mut last_was_separator = false;
+
         while let Some(ch) = self.current_char() {
-            if ch.is_numeric() {
+            if ch == '_' {
+                if count == 0 {
+                    report(error, "Separador numérico não pode iniciar a sequência de dígitos".to_string());
+                } else if last_was_separator {
+                    report(error, "Múltiplos separadores numéricos consecutivos não são permitidos".to_string());
+                }
+                last_was_separator = true;
                 self.advance();
-            } else if ch == '.' {
-                dot_count += 1;
+            } else if ch.is_digit(radix) {
+                count += 1;
+                last_was_separator = false;
+                self.advance();
+            } else if ch.is_ascii_digit() {
+                report(error, format!("Dígito '{}' inválido para literal {}", ch, name));
+                last_was_separator = false;
                 self.advance();
             } else {
                 break;
             }
         }
-        
-        let value = self.input[start..self.position].to_string();
-        let malformed = if dot_count > 1 {
-            Some(format!("Número com múltiplos pontos decimais ({})", dot_count))
-        } else {
-            None
-        };
-        
-        (value, malformed)
+
+        if last_was_separator {
+            report(error, "Separador numérico não pode ficar no fim do número".to_string());
+        }
+
+        count
     }
 
-    fn consume_string(&mut self, quote: char) -> (String, Option<String>) {
-        let start = self.position;
+    /// Consome a parte fracionária e o expoente de um decimal.
+    /// Retorna `true` se o número continua sendo inteiro (sem `.` nem expoente).
+    fn consume_decimal_tail(&mut self, error: &mut Option<String>, leading_dot: bool) -> bool {
+        let mut is_integer = true;
+
+        if self.current_char() == Some('.') {
+            // `1..toString()`: o segundo ponto é acesso a membro
+            is_integer = false;
+            self.advance();
+            if self.current_char() == Some('_') {
+                report(error, "Separador numérico não pode ficar junto ao ponto decimal".to_string());
+            }
+            let digits = self.consume_digits(10, "decimal", error);
+            if leading_dot && digits == 0 {
+                report(error, "Dígito esperado após o ponto decimal".to_string());
+            }
+        }
+
+        if matches!(self.current_char(), Some('e' | 'E')) {
+            is_integer = false;
+            self.advance();
+            if matches!(self.current_char(), Some('+' | '-')) {
+                self.advance();
+            }
+            if self.consume_digits(10, "decimal", error) == 0 {
+                report(error, "Expoente sem dígitos".to_string());
+            }
+        }
+
+        is_integer
+    }
+
+    fn consume_string(&mut self, quote: char) -> Option<String> {
         let start = self.position;
         self.advance();
         let mut terminated = false;
@@ -302,6 +714,10 @@ impl<'a> Lexer<'a> {
                 break;
             } else if ch == '\\' {
                 self.advance();
+                // `\` seguido de `\r\n` é uma única continuação de linha
+                if self.current_char() == Some('\r') && self.peek_char() == Some('\n') {
+                    self.advance();
+                }
                 if self.current_char().is_some() {
                     self.advance();
                 }
@@ -314,18 +730,59 @@ impl<'a> Lexer<'a> {
             }
         }
 
-        let value = self.input[start..self.position].to_string();
-        let malformed = if !terminated {
+        if !terminated {
             Some("String não terminada".to_string())
+        } else {
+            unescape(&self.input[start + 1..self.position - 1]).1
+        }
+    }
+
+    /// Lê um pedaço de template começando em `` ` `` ou em `}` (retomada após uma
+    /// substituição) até o próximo `` ` `` ou `${`. Templates podem ocupar várias linhas.
+    fn consume_template(&mut self) -> (TokenKind, Option<String>) {
+        let opens_template = self.current_char() == Some('`');
+        self.advance();
+        let mut terminated = false;
+        let mut substitution = false;
+
+        while let Some(ch) = self.current_char() {
+            if ch == '`' {
+                self.advance();
+                terminated = true;
+                break;
+            } else if ch == '$' && self.peek_char() == Some('{') {
+                self.advance();
+                self.advance();
+                self.brace_stack.push(true);
+                terminated = true;
+                substitution = true;
+                break;
+            } else if ch == '\\' {
+                self.advance();
+                if self.current_char().is_some() {
+                    self.advance();
+                }
+            } else {
+                self.advance();
+            }
+        }
+
+        let kind = match (opens_template, substitution) {
+            (true, false) => TokenKind::NoSubstitutionTemplate,
+            (true, true) => TokenKind::TemplateHead,
+            (false, true) => TokenKind::TemplateMiddle,
+            (false, false) => TokenKind::TemplateTail,
+        };
+        let malformed = if !terminated {
+            Some("Template string não terminada".to_string())
         } else {
             None
         };
 
-        (value, malformed)
+        (kind, malformed)
     }
 
-    fn consume_comment(&mut self) -> (String, Option<String>) {
-        let start = self.position;
+    fn consume_comment(&mut self) -> Option<String> {
         let mut terminated = true;
 
         if self.peek_char() == Some('/') {
@@ -357,143 +814,280 @@ impl<'a> Lexer<'a> {
             }
         }
 
-        let value = self.input[start..self.position].to_string();
-        let malformed = if !terminated {
+        if !terminated {
             Some("Comentário multilinha não fechado".to_string())
         } else {
             None
-        };
-
-        (value, malformed)
+        }
     }
 
-    fn consume_operator(&mut self) -> String {
-        let start = self.position;
-        let ch = self.current_char().unwrap();
-        self.advance();
+    fn consume_regex(&mut self) -> Option<String> {
+        let mut error: Option<String> = None;
+        let mut in_class = false;
+        let mut terminated = false;
 
-        match ch {
-            '=' => {
-                if self.current_char() == Some('=') {
+        self.advance();
+        while let Some(ch) = self.current_char() {
+            match ch {
+                '\n' | '\r' => break,
+                '\\' => {
                     self.advance();
-                    if self.current_char() == Some('=') {
+                    if matches!(self.current_char(), Some(c) if c != '\n' && c != '\r') {
                         self.advance();
                     }
-                } else if self.current_char() == Some('>') {
-                    self.advance();
                 }
-            }
-            '!' => {
-                if self.current_char() == Some('=') {
+                '[' => {
+                    in_class = true;
                     self.advance();
-                    if self.current_char() == Some('=') {
-                        self.advance();
-                    }
                 }
-            }
-            '<' | '>' => {
-                if self.current_char() == Some('=') {
-                    self.advance();
-                } else if self.current_char() == Some(ch) {
+                ']' => {
+                    in_class = false;
                     self.advance();
                 }
-            }
-            '&' | '|' => {
-                if self.current_char() == Some(ch) {
+                '/' if !in_class => {
                     self.advance();
+                    terminated = true;
+                    break;
                 }
+                _ => self.advance(),
             }
-            '+' | '-' => {
-                if self.current_char() == Some(ch) || self.current_char() == Some('=') {
-                    self.advance();
-                }
+        }
+
+        if !terminated {
+            let message = if in_class {
+                "Classe de caracteres não terminada na expressão regular"
+            } else {
+                "Expressão regular não terminada"
+            };
+            return Some(message.to_string());
+        }
+
+        // Flags: d, g, i, m, s, u, v, y — sem repetição, e `u` não combina com `v`
+        let mut seen = String::new();
+        while let Some(ch) = self.current_char() {
+            if !(ch.is_alphanumeric() || ch == '_' || ch == '$') {
+                break;
             }
-            '*' => {
-                if self.current_char() == Some('=') || self.current_char() == Some('*') {
-                    self.advance();
-                }
+            if !"dgimsuvy".contains(ch) {
+                report(&mut error, format!("Flag '{}' inválida na expressão regular", ch));
+            } else if seen.contains(ch) {
+                report(&mut error, format!("Flag '{}' repetida na expressão regular", ch));
+            } else if (ch == 'u' && seen.contains('v')) || (ch == 'v' && seen.contains('u')) {
+                report(&mut error, "As flags 'u' e 'v' não podem ser usadas juntas".to_string());
             }
-            _ => {}
+            seen.push(ch);
+            self.advance();
         }
 
-        self.input[start..self.position].to_string()
+        error
     }
 
-    fn is_keyword(&self, value: &str) -> bool {
-        matches!(
-            value,
-            "abstract"
-                | "any"
-                | "as"
-                | "asserts"
-                | "bigint"
-                | "boolean"
-                | "break"
-                | "case"
-                | "catch"
-                | "class"
-                | "const"
-                | "continue"
-                | "debugger"
-                | "declare"
-                | "default"
-                | "delete"
-                | "do"
-                | "else"
-                | "enum"
-                | "export"
-                | "extends"
-                | "false"
-                | "finally"
-                | "for"
-                | "from"
-                | "function"
-                | "get"
-                | "if"
-                | "implements"
-                | "import"
-                | "in"
-                | "infer"
-                | "instanceof"
-                | "interface"
-                | "is"
-                | "keyof"
-                | "let"
-                | "module"
-                | "namespace"
-                | "never"
-                | "new"
-                | "null"
-                | "number"
-                | "object"
-                | "of"
-                | "package"
-                | "private"
-                | "protected"
-                | "public"
-                | "readonly"
-                | "require"
-                | "return"
-                | "set"
-                | "static"
-                | "string"
-                | "super"
-                | "switch"
-                | "symbol"
-                | "this"
-                | "throw"
-                | "true"
-                | "try"
-                | "type"
-                | "typeof"
-                | "undefined"
-                | "unique"
-                | "unknown"
-                | "var"
-                | "void"
-                | "while"
-                | "with"
-                | "yield"
-        )
+    /// Decide pelo token recém-lido, atualizando a pilha de parênteses. Além da
+    /// tabela de `allows_regex_after`, depende do contexto: `obj.return` é um nome de
+    /// propriedade, o `)` de `if (x)` é seguido de uma instrução e `of` só é operador
+    /// no cabeçalho de um `for`.
+    fn regex_allowed_after(&mut self, token: &Token) -> bool {
+        let closes_statement_head = match token.kind {
+            TokenKind::LParen => {
+                let opens_statement_head = match self.previous {
+                    [Some(TokenKind::If | TokenKind::While | TokenKind::For | TokenKind::With), _] => true,
+                    // `for await (`
+                    [Some(TokenKind::Identifier), Some(TokenKind::For)] => true,
+                    _ => false,
+                };
+                self.paren_stack.push(opens_statement_head);
+                false
+            }
+            TokenKind::RParen => self.paren_stack.pop().unwrap_or(false),
+            _ => false,
+        };
+
+        if matches!(self.previous[0], Some(TokenKind::Dot | TokenKind::QuestionDot)) {
+            return false;
+        }
+        match token.kind {
+            TokenKind::RParen => closes_statement_head,
+            TokenKind::Of => self.paren_stack.last() == Some(&true),
+            TokenKind::Identifier => token.value == "await",
+            kind => Self::allows_regex_after(kind),
+        }
+    }
+
+    /// Depois destes tokens um `/` só pode iniciar uma expressão regular;
+    /// depois de um operando (identificador, literal, `)`, `]`) ele é divisão.
+    fn allows_regex_after(kind: TokenKind) -> bool {
+        match kind {
+            TokenKind::Identifier
+            | TokenKind::PrivateName
+            | TokenKind::NumericLiteral
+            | TokenKind::BigIntLiteral
+            | TokenKind::StringLiteral
+            | TokenKind::RegExpLiteral
+            | TokenKind::NoSubstitutionTemplate
+            | TokenKind::TemplateTail
+            | TokenKind::RParen
+            | TokenKind::RBracket
+            | TokenKind::PlusPlus
+            | TokenKind::MinusMinus => false,
+            TokenKind::Return
+            | TokenKind::Typeof
+            | TokenKind::Instanceof
+            | TokenKind::In
+            | TokenKind::Of
+            | TokenKind::New
+            | TokenKind::Delete
+            | TokenKind::Void
+            | TokenKind::Throw
+            | TokenKind::Case
+            | TokenKind::Do
+            | TokenKind::Else
+            | TokenKind::Yield
+            | TokenKind::Extends => true,
+            kind => !kind.is_keyword(),
+        }
+    }
+
+    /// Maximal munch: sempre consome o maior pontuador possível da tabela.
+    fn consume_operator(&mut self) -> TokenKind {
+        let rest = &self.input[self.position..];
+        let value = OPERATORS
+            .iter()
+            .find(|op| {
+                // `a?.5:0` é um ternário, não encadeamento opcional
+                rest.starts_with(**op)
+                    && !(**op == "?." && rest[2..].starts_with(|c: char| c.is_ascii_digit()))
+            })
+            .expect("todo caractere despachado para consume_operator está na tabela");
+
+        for _ in 0..value.len() {
+            self.advance();
+        }
+        TokenKind::from_punctuator(value).unwrap()
+    }
+}
+
+/// Pontuadores de operador do ECMAScript/TypeScript, do mais longo para o mais curto.
+/// As chaves, parênteses, colchetes, `;`, `,` e `@` são tratados direto em `next_token`.
+const OPERATORS: &[&str] = &[
+    ">>>=",
+    "...", "===", "!==", "**=", "<<=", ">>=", ">>>", "&&=", "||=", "??=",
+    "=>", "==", "!=", "<=", ">=", "&&", "||", "??", "?.", "++", "--", "**", "<<", ">>",
+    "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=",
+    "<", ">", "+", "-", "*", "/", "%", "&", "|", "^", "!", "~", "?", ":", "=", ".",
+];
+
+/// Decodifica as sequências de escape do conteúdo de uma string (sem as aspas).
+/// Devolve o texto decodificado e o primeiro escape inválido, se houver; o
+/// escape inválido é mantido como está no texto. Metades de par substituto
+/// (`\uD800`) sem o par viram `U+FFFD`, já que `String` só guarda UTF-8 válido.
+pub fn unescape(body: &str) -> (String, Option<String>) {
+    let mut value = String::with_capacity(body.len());
+    let mut error = None;
+    let mut chars = body.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            value.push(ch);
+            continue;
+        }
+        let Some(escape) = chars.next() else {
+            value.push('\\');
+            break;
+        };
+        match escape {
+            'n' => value.push('\n'),
+            't' => value.push('\t'),
+            'r' => value.push('\r'),
+            'b' => value.push('\u{8}'),
+            'f' => value.push('\u{c}'),
+            'v' => value.push('\u{b}'),
+            '0' if !chars.peek().is_some_and(|c| c.is_ascii_digit()) => value.push('\0'),
+            // Continuações de linha não entram no valor
+            '\r' => {
+                chars.next_if_eq(&'\n');
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            '8' | '9' => {
+                report(&mut error, format!("Escape '\\{}' não é permitido em modo estrito", escape));
+                value.push('\\');
+                value.push(escape);
+            }
+            '0'..='7' => {
+                report(&mut error, "Escapes octais não são permitidos em modo estrito".to_string());
+                value.push('\\');
+                value.push(escape);
+            }
+            'x' => match read_hex(&mut chars, 2) {
+                Some(code) => value.push(char::from_u32(code).unwrap()),
+                None => {
+                    report(&mut error, "Escape hexadecimal inválido: esperados dois dígitos depois de '\\x'".to_string());
+                    value.push_str("\\x");
+                }
+            },
+            'u' => match read_unicode_escape(&mut chars) {
+                Some(code) if (0xD800..0xDC00).contains(&code) => {
+                    // Metade alta de um par substituto: junta com a metade baixa, se vier
+                    let mut lookahead = chars.clone();
+                    let low = (lookahead.next() == Some('\\') && lookahead.next() == Some('u'))
+                        .then(|| read_unicode_escape(&mut lookahead))
+                        .flatten()
+                        .filter(|low| (0xDC00..0xE000).contains(low));
+                    match low {
+                        Some(low) => {
+                            chars = lookahead;
+                            value.push(char::from_u32(0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00)).unwrap());
+                        }
+                        None => value.push('\u{FFFD}'),
+                    }
+                }
+                Some(code) => value.push(char::from_u32(code).unwrap_or('\u{FFFD}')),
+                None => {
+                    report(&mut error, "Escape Unicode inválido: use '\\uXXXX' ou '\\u{X}' até '\\u{10FFFF}'".to_string());
+                    value.push_str("\\u");
+                }
+            },
+            other => value.push(other),
+        }
+    }
+
+    (value, error)
+}
+
+/// `XXXX` ou `{X...}` depois de `\u`. Só avança `chars` se o escape for válido.
+fn read_unicode_escape(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<u32> {
+    if chars.peek() != Some(&'{') {
+        return read_hex(chars, 4);
+    }
+    let mut lookahead = chars.clone();
+    lookahead.next();
+    let mut code: u32 = 0;
+    let mut digits = 0;
+    while let Some(digit) = lookahead.peek().and_then(|c| c.to_digit(16)) {
+        code = code.saturating_mul(16).saturating_add(digit);
+        digits += 1;
+        lookahead.next();
+    }
+    if digits == 0 || code > 0x10FFFF || lookahead.next() != Some('}') {
+        return None;
+    }
+    *chars = lookahead;
+    Some(code)
+}
+
+/// Exatamente `count` dígitos hexadecimais. Só avança `chars` se todos existirem.
+fn read_hex(chars: &mut std::iter::Peekable<std::str::Chars>, count: usize) -> Option<u32> {
+    let mut lookahead = chars.clone();
+    let mut code = 0;
+    for _ in 0..count {
+        code = code * 16 + lookahead.next()?.to_digit(16)?;
+    }
+    *chars = lookahead;
+    Some(code)
+}
+
+/// Guarda apenas o primeiro problema encontrado em um token.
+fn report(error: &mut Option<String>, message: String) {
+    if error.is_none() {
+        *error = Some(message);
     }
 }
@@ -2,11 +2,8 @@ use clap::{Arg, Command};
 use std::io::{self, Read};
 use std::fs;
 
-mod lexer;
-mod parser;
-
-use lexer::Lexer;
-use parser::Parser;
+use rustots::lexer::Lexer;
+use rustots::parser::Parser;
 
 fn main() -> anyhow::Result<()> {
     let matches = Command::new("rustots")
@@ -46,16 +43,10 @@ fn main() -> anyhow::Result<()> {
     let tokens = lexer.tokenize();
 
     let mut parser = Parser::new(tokens.clone());
-    let mut parse_error: Option<String> = None;
-    let ast = match parser.parse() {
-        Ok(program) => Some(program),
-        Err(e) => {
-            let error_msg = format!("{:?}", e);
-            eprintln!("Erro de Análise: {}", error_msg);
-            parse_error = Some(error_msg);
-            None
-        }
-    };
+    let (ast, parse_errors) = parser.parse();
+    for e in &parse_errors {
+        eprintln!("Erro de Análise: {}", e);
+    }
 
     let mut result_tokens = tokens;
     if matches.get_flag("only-malformed") {
@@ -65,7 +56,7 @@ fn main() -> anyhow::Result<()> {
     let result = serde_json::json!({
         "tokens": result_tokens,
         "ast": ast,
-        "error": parse_error
+        "errors": parse_errors
     });
     println!("{}", serde_json::to_string_pretty(&result)?);
 
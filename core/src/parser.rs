@@ -1,467 +1,3342 @@
-use crate::lexer::{Token, TokenType};
+use crate::lexer::{unescape, Token, TokenKind};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+mod errors;
+mod literals;
+mod modules;
+mod patterns;
+mod types;
+
+use errors::ExpectedTokens;
+pub use errors::{ErrorCode, ParseError};
+pub use literals::LiteralValue;
+pub use modules::{ExportDefault, ExportSpecifier, ImportAttribute, ImportSpecifier, ModuleReference};
+pub use patterns::Pattern;
+pub use types::{TypeMember, TypeNode, TypeParameter, TypeQueryName};
+
+/// Trecho do código ocupado por um nó: deslocamentos em bytes e linha/coluna de
+/// início e de fim, contadas a partir de 1. O fim é exclusivo (`end`, `end_column`).
+/// Os campos são `u32` para manter os nós pequenos (arquivos de até 4 GB).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Span {
+    pub start: u32,
+    pub end: u32,
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+}
 
-#[derive(Debug, Serialize, Deserialize)]
-pub enum ParseError {
-    UnexpectedToken { expected: String, found: String },
-    UnexpectedEOF,
-    InvalidSyntax(String),
+impl Span {
+    fn of_token(token: &Token) -> Self {
+        Span {
+            start: token.position.start as u32,
+            end: token.position.end as u32,
+            start_line: token.position.line as u32,
+            start_column: token.position.column as u32,
+            end_line: token.position.end_line as u32,
+            end_column: token.position.end_column as u32,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Program {
     pub body: Vec<Statement>,
+    pub span: Span,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[allow(clippy::enum_variant_names)]
 pub enum Statement {
     FunctionDeclaration {
-        id: Identifier,
-        params: Vec<Identifier>,
-        return_type: Option<String>,
+        id: Option<Identifier>, // só é opcional em `export default function () {}`
+        type_parameters: Option<Vec<TypeParameter>>,
+        params: Vec<Param>,
+        return_type: Option<TypeNode>,
         body: BlockStatement,
+        r#async: bool,
+        generator: bool,
+        span: Span,
     },
-    VariableDeclaration {
-        kind: String, // const, let, var
-        declarations: Vec<VariableDeclarator>,
-    },
+    VariableDeclaration(VariableDeclaration),
     ExpressionStatement {
         expression: Expression,
+        span: Span,
     },
     ReturnStatement {
         argument: Option<Expression>,
+        span: Span,
     },
     BlockStatement(BlockStatement),
+    IfStatement {
+        test: Expression,
+        consequent: Box<Statement>,
+        alternate: Option<Box<Statement>>,
+        span: Span,
+    },
+    ForStatement {
+        init: Option<ForInit>,
+        test: Option<Expression>,
+        update: Option<Expression>,
+        body: Box<Statement>,
+        span: Span,
+    },
+    ForInStatement {
+        left: ForInit,
+        right: Expression,
+        body: Box<Statement>,
+        span: Span,
+    },
+    ForOfStatement {
+        left: ForInit,
+        right: Expression,
+        body: Box<Statement>,
+        r#await: bool, // `for await (...)`
+        span: Span,
+    },
+    WhileStatement {
+        test: Expression,
+        body: Box<Statement>,
+        span: Span,
+    },
+    DoWhileStatement {
+        body: Box<Statement>,
+        test: Expression,
+        span: Span,
+    },
+    SwitchStatement {
+        discriminant: Expression,
+        cases: Vec<SwitchCase>,
+        span: Span,
+    },
+    TryStatement {
+        block: BlockStatement,
+        handler: Option<CatchClause>,
+        finalizer: Option<BlockStatement>,
+        span: Span,
+    },
+    ThrowStatement {
+        argument: Expression,
+        span: Span,
+    },
+    BreakStatement {
+        label: Option<Identifier>,
+        span: Span,
+    },
+    ContinueStatement {
+        label: Option<Identifier>,
+        span: Span,
+    },
+    LabeledStatement {
+        label: Identifier,
+        body: Box<Statement>,
+        span: Span,
+    },
+    EmptyStatement {
+        span: Span,
+    },
+    DebuggerStatement {
+        span: Span,
+    },
+    ClassDeclaration(Class),
+    TSInterfaceDeclaration {
+        id: Identifier,
+        type_parameters: Option<Vec<TypeParameter>>,
+        extends: Vec<InterfaceHeritage>,
+        body: Vec<TypeMember>,
+        span: Span,
+    },
+    TSTypeAliasDeclaration {
+        id: Identifier,
+        type_parameters: Option<Vec<TypeParameter>>,
+        type_annotation: TypeNode,
+        span: Span,
+    },
+    TSEnumDeclaration {
+        id: Identifier,
+        r#const: bool,
+        members: Vec<EnumMember>,
+        span: Span,
+    },
+    ImportDeclaration {
+        specifiers: Vec<ImportSpecifier>,
+        source: Expression,
+        import_kind: String, // value ou type
+        attributes: Vec<ImportAttribute>,
+        span: Span,
+    },
+    ExportNamedDeclaration {
+        declaration: Option<Box<Statement>>,
+        specifiers: Vec<ExportSpecifier>,
+        source: Option<Expression>,
+        export_kind: String, // value ou type
+        attributes: Vec<ImportAttribute>,
+        span: Span,
+    },
+    ExportDefaultDeclaration {
+        declaration: ExportDefault,
+        span: Span,
+    },
+    ExportAllDeclaration {
+        exported: Option<Expression>,
+        source: Expression,
+        export_kind: String,
+        attributes: Vec<ImportAttribute>,
+        span: Span,
+    },
+    /// `export = x;`
+    TSExportAssignment {
+        expression: Expression,
+        span: Span,
+    },
+    /// `import x = require("m");` e `import x = ns.y;`
+    TSImportEqualsDeclaration {
+        id: Identifier,
+        module_reference: ModuleReference,
+        import_kind: String,
+        is_export: bool,
+        span: Span,
+    },
+    /// Trecho com erro de sintaxe, pulado até um ponto seguro para continuar
+    Error {
+        span: Span,
+    },
+}
+
+/// `extends A<T>, ns.B` de uma interface ou `implements A<T>, ns.B` de uma classe
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InterfaceHeritage {
+    pub expression: Expression,
+    pub type_arguments: Option<Vec<TypeNode>>,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EnumMember {
+    pub id: Expression, // Identificador, string ou `[chave]` computada
+    pub computed: bool,
+    pub initializer: Option<Expression>,
+    pub span: Span,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct BlockStatement {
     pub body: Vec<Statement>,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Param {
+    pub decorators: Vec<Decorator>, // `@Inject(X) x: X`
+    pub id: Pattern, // `x`, `{ a }`, `[a, b]`, `...rest` ou `x = padrão`
+    pub optional: bool,
+    pub type_annotation: Option<TypeNode>,
+    /// Em um construtor, `private x: T` ou `readonly x: T` também declaram a propriedade.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_property: Option<ParameterProperty>,
+    pub span: Span,
+}
+
+/// Modificadores de uma propriedade de parâmetro (`constructor(private readonly x: T)`).
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ParameterProperty {
+    pub accessibility: Option<String>, // public, private, protected
+    pub readonly: bool,
+    pub r#override: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Class {
+    pub decorators: Vec<Decorator>,
+    pub id: Option<Identifier>,
+    pub type_parameters: Option<Vec<TypeParameter>>,
+    pub super_class: Option<Box<Expression>>,
+    pub super_type_arguments: Option<Vec<TypeNode>>, // `extends Base<T>`
+    pub implements: Vec<InterfaceHeritage>,
+    pub r#abstract: bool,
+    pub body: Vec<ClassMember>,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ClassMember {
+    PropertyDefinition {
+        decorators: Vec<Decorator>,
+        key: Expression,
+        computed: bool,
+        optional: bool,
+        definite: bool, // `x!: T`
+        #[serde(flatten)]
+        modifiers: Modifiers,
+        type_annotation: Option<TypeNode>,
+        value: Option<Expression>,
+        span: Span,
+    },
+    MethodDefinition {
+        decorators: Vec<Decorator>,
+        key: Expression,
+        computed: bool,
+        optional: bool,
+        kind: String, // constructor, method, get, set
+        #[serde(flatten)]
+        modifiers: Modifiers,
+        value: Function,
+        span: Span,
+    },
+    StaticBlock {
+        body: Vec<Statement>,
+        span: Span,
+    },
+    TSIndexSignature {
+        parameters: Vec<Param>,
+        type_annotation: Option<TypeNode>,
+        #[serde(flatten)]
+        modifiers: Modifiers,
+        span: Span,
+    },
+}
+
+/// `@expressão` antes de uma classe, de um membro de classe ou de um parâmetro.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub struct Decorator {
+    pub expression: Expression,
+    pub span: Span,
+}
+
+/// Modificadores de membros de classe (e de propriedades de parâmetro).
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Modifiers {
+    pub accessibility: Option<String>, // public, private, protected
+    pub r#static: bool,
+    pub r#abstract: bool,
+    pub readonly: bool,
+    pub r#override: bool,
+    pub declare: bool,
+}
+
+impl Modifiers {
+    fn is_empty(&self) -> bool {
+        self.accessibility.is_none()
+            && !self.r#static
+            && !self.r#abstract
+            && !self.readonly
+            && !self.r#override
+            && !self.declare
+    }
+}
+
+/// Parâmetros, tipo de retorno e corpo de um método.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Function {
+    pub type_parameters: Option<Vec<TypeParameter>>,
+    pub params: Vec<Param>,
+    pub return_type: Option<TypeNode>,
+    pub body: Option<BlockStatement>, // None em métodos abstratos e assinaturas de sobrecarga
+    pub r#async: bool,
+    pub generator: bool,
+    pub span: Span,
+}
+
+/// Corpo de uma arrow function: um bloco ou uma única expressão (`x => x * 2`).
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ArrowBody {
+    BlockStatement(BlockStatement),
+    #[serde(untagged)]
+    Expression(Box<Expression>),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VariableDeclaration {
+    pub kind: String, // const, let, var
+    pub declare: bool, // `declare const x: T;`, sem valor inicial obrigatório
+    pub declarations: Vec<VariableDeclarator>,
+    pub span: Span,
+}
+
+/// Cabeçalho de um `for`: o `init` de um `for (;;)` ou o lado esquerdo
+/// de um `for-in`/`for-of`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ForInit {
+    VariableDeclaration(VariableDeclaration),
+    /// Alvo de um for-in/for-of sem declaração (`for ([a, b] of xs)`, `for (x.y in o)`)
+    #[serde(untagged)]
+    Pattern(Pattern),
+    #[serde(untagged)]
+    Expression(Expression),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SwitchCase {
+    pub test: Option<Expression>, // None para `default`
+    pub consequent: Vec<Statement>,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CatchClause {
+    pub param: Option<Pattern>,
+    pub type_annotation: Option<TypeNode>,
+    pub body: BlockStatement,
+    pub span: Span,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct VariableDeclarator {
-    pub id: Identifier,
+    pub id: Pattern,
+    pub definite: bool, // `let x!: T`
+    pub type_annotation: Option<TypeNode>,
     pub init: Option<Expression>,
+    pub span: Span,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
+#[allow(clippy::enum_variant_names)]
 pub enum Expression {
     BinaryExpression {
         operator: String,
         left: Box<Expression>,
         right: Box<Expression>,
+        span: Span,
     },
     CallExpression {
         callee: Box<Expression>,
+        type_arguments: Option<Vec<TypeNode>>, // `f<T>(x)`
         arguments: Vec<Expression>,
+        optional: bool, // `f?.()`
+        span: Span,
     },
     MemberExpression {
         object: Box<Expression>,
         property: Box<Expression>,
         computed: bool,
+        optional: bool, // `a?.b` e `a?.[b]`
+        span: Span,
+    },
+    NewExpression {
+        callee: Box<Expression>,
+        type_arguments: Option<Vec<TypeNode>>, // `new Map<K, V>()`
+        arguments: Vec<Expression>,            // vazio em `new Foo`
+        span: Span,
+    },
+    ThisExpression {
+        span: Span,
+    },
+    /// `super` em `super(...)`, `super.x` e `super[x]`
+    Super {
+        span: Span,
+    },
+    /// `new.target` e `import.meta`
+    MetaProperty {
+        meta: Identifier,
+        property: Identifier,
+        span: Span,
+    },
+    /// `import(fonte)` ou `import(fonte, opções)`
+    ImportExpression {
+        source: Box<Expression>,
+        options: Option<Box<Expression>>,
+        span: Span,
+    },
+    /// Encadeamento com ao menos um `?.`. Envolve o encadeamento inteiro, que é
+    /// interrompido por completo quando um dos objetos é `null` ou `undefined`.
+    ChainExpression {
+        expression: Box<Expression>,
+        span: Span,
     },
+    /// Strings, números, BigInts, `true`, `false` e `null`
     Literal {
-        value: String,
+        value: LiteralValue,
         raw: String,
+        span: Span,
+    },
+    RegExpLiteral {
+        pattern: String,
+        flags: String,
+        raw: String,
+        span: Span,
+    },
+    TemplateLiteral(TemplateLiteral),
+    TaggedTemplateExpression {
+        tag: Box<Expression>,
+        type_arguments: Option<Vec<TypeNode>>,
+        quasi: TemplateLiteral,
+        span: Span,
     },
     Identifier(Identifier),
+    PrivateIdentifier {
+        name: String, // sem o `#`
+        span: Span,
+    },
+    ClassExpression(Class),
     UnaryExpression {
         operator: String,
         argument: Box<Expression>,
         prefix: bool,
+        span: Span,
+    },
+    FunctionExpression {
+        id: Option<Identifier>,
+        type_parameters: Option<Vec<TypeParameter>>,
+        params: Vec<Param>,
+        return_type: Option<Box<TypeNode>>,
+        body: BlockStatement,
+        r#async: bool,
+        generator: bool,
+        span: Span,
+    },
+    ArrowFunctionExpression {
+        type_parameters: Option<Vec<TypeParameter>>,
+        params: Vec<Param>,
+        return_type: Option<Box<TypeNode>>,
+        body: ArrowBody,
+        r#async: bool,
+        expression: bool, // corpo é uma expressão, não um bloco
+        span: Span,
+    },
+    AwaitExpression {
+        argument: Box<Expression>,
+        span: Span,
+    },
+    AssignmentExpression {
+        operator: String,
+        left: Box<Pattern>,
+        right: Box<Expression>,
+        span: Span,
+    },
+    UpdateExpression {
+        operator: String,
+        argument: Box<Expression>,
+        prefix: bool,
+        span: Span,
+    },
+    ConditionalExpression {
+        test: Box<Expression>,
+        consequent: Box<Expression>,
+        alternate: Box<Expression>,
+        span: Span,
+    },
+    /// `||`, `&&` e `??`
+    LogicalExpression {
+        operator: String,
+        left: Box<Expression>,
+        right: Box<Expression>,
+        span: Span,
+    },
+    SequenceExpression {
+        expressions: Vec<Expression>,
+        span: Span,
+    },
+    ObjectExpression {
+        properties: Vec<ObjectProperty>,
+        span: Span,
+    },
+    ArrayExpression {
+        elements: Vec<Option<Expression>>, // `None` nos buracos (`[1, , 3]`)
+        span: Span,
+    },
+    /// `...x` em arrays e argumentos de chamadas
+    SpreadElement {
+        argument: Box<Expression>,
+        span: Span,
+    },
+    YieldExpression {
+        argument: Option<Box<Expression>>,
+        delegate: bool, // `yield*`
+        span: Span,
+    },
+    /// `x as T` e `x as const` (com `TSConstType`)
+    TSAsExpression {
+        expression: Box<Expression>,
+        type_annotation: Box<TypeNode>,
+        span: Span,
+    },
+    TSSatisfiesExpression {
+        expression: Box<Expression>,
+        type_annotation: Box<TypeNode>,
+        span: Span,
+    },
+    /// `x!`
+    TSNonNullExpression {
+        expression: Box<Expression>,
+        span: Span,
+    },
+    /// `<T>x` e `<const>x`
+    TSTypeAssertion {
+        type_annotation: Box<TypeNode>,
+        expression: Box<Expression>,
+        span: Span,
+    },
+}
+
+impl Expression {
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::TemplateLiteral(TemplateLiteral { span, .. })
+            | Expression::Identifier(Identifier { span, .. })
+            | Expression::ClassExpression(Class { span, .. })
+            | Expression::BinaryExpression { span, .. }
+            | Expression::CallExpression { span, .. }
+            | Expression::MemberExpression { span, .. }
+            | Expression::ChainExpression { span, .. }
+            | Expression::NewExpression { span, .. }
+            | Expression::ThisExpression { span }
+            | Expression::Super { span }
+            | Expression::MetaProperty { span, .. }
+            | Expression::ImportExpression { span, .. }
+            | Expression::Literal { span, .. }
+            | Expression::RegExpLiteral { span, .. }
+            | Expression::TaggedTemplateExpression { span, .. }
+            | Expression::PrivateIdentifier { span, .. }
+            | Expression::UnaryExpression { span, .. }
+            | Expression::FunctionExpression { span, .. }
+            | Expression::ArrowFunctionExpression { span, .. }
+            | Expression::AwaitExpression { span, .. }
+            | Expression::AssignmentExpression { span, .. }
+            | Expression::UpdateExpression { span, .. }
+            | Expression::ConditionalExpression { span, .. }
+            | Expression::LogicalExpression { span, .. }
+            | Expression::SequenceExpression { span, .. }
+            | Expression::ObjectExpression { span, .. }
+            | Expression::ArrayExpression { span, .. }
+            | Expression::SpreadElement { span, .. }
+            | Expression::YieldExpression { span, .. }
+            | Expression::TSAsExpression { span, .. }
+            | Expression::TSSatisfiesExpression { span, .. }
+            | Expression::TSNonNullExpression { span, .. }
+            | Expression::TSTypeAssertion { span, .. } => *span,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ObjectProperty {
+    Property {
+        key: Box<Expression>,
+        value: Expression,
+        computed: bool,
+        shorthand: bool, // `{ a }`
+        method: bool,    // `{ m() {} }`
+        kind: String,    // init, get ou set
+        span: Span,
+    },
+    SpreadElement {
+        argument: Expression,
+        span: Span,
     },
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TemplateLiteral {
+    pub quasis: Vec<TemplateElement>,
+    pub expressions: Vec<Expression>,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TemplateElement {
+    pub raw: String,
+    /// Texto com os escapes interpretados; `None` em um template marcado com escape inválido
+    pub cooked: Option<String>,
+    pub tail: bool,
+    pub span: Span,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Identifier {
     pub name: String,
+    pub span: Span,
 }
 
 pub struct Parser {
     tokens: Vec<Token>,
+    // Se há uma quebra de linha antes de cada token de `tokens`
+    newline_before: Vec<bool>,
     current: usize,
+    context: StatementContext,
+    // Dentro do `extends` de um tipo condicional, onde `A extends B ? ...` não é
+    // permitido sem parênteses e `infer U extends C` ganha restrição.
+    in_conditional_extends: bool,
+    // Tokens originais de `>>`, `>=`... que foram quebrados ao fechar argumentos de
+    // tipo, para que um `rollback` os restaure.
+    split_tokens: Vec<(usize, Token)>,
+    // Falso só no inicializador de um `for`, onde `in` encerra a expressão
+    allow_in: bool,
+    // Trecho do último token consumido, que marca o fim do nó em construção
+    last_token: Span,
+    // Tokens testados sem sucesso na posição atual, listados nos erros
+    expected_tokens: ExpectedTokens,
+    // Erros já recuperados, devolvidos junto com a árvore
+    errors: Vec<ParseError>,
+    // Primeiro `{ a = 1 }` de um literal que ainda pode virar desestruturação
+    cover_initializer: Option<Span>,
+    // Início dos `...x` seguidos de vírgula em arrays literais, que não podem virar rest
+    spreads_before_comma: HashSet<u32>,
+    // Expressões abertas e ainda não terminadas, limitado por `MAX_NESTING_DEPTH`
+    depth: usize,
+    // Logo depois do `?` de uma condicional, onde uma arrow com tipo de retorno
+    // precisa ser seguida pelo `:`
+    in_conditional_consequent: bool,
+}
+
+/// Quantas expressões podem estar abertas umas dentro das outras. Cada nível usa
+/// várias chamadas recursivas; sem limite, `((((...))))` bem fundo estoura a pilha.
+const MAX_NESTING_DEPTH: usize = 128;
+
+/// Posição salva do parser para análise especulativa.
+#[derive(Clone, Copy)]
+struct Checkpoint {
+    current: usize,
+    splits: usize,
+    last_token: Span,
+    errors: usize,
+}
+
+/// Variável ou propriedade, possivelmente dentro de `x!`, `x as T` ou `<T>x`.
+fn is_simple_target(expression: &Expression) -> bool {
+    match expression {
+        Expression::Identifier(_) | Expression::MemberExpression { .. } => true,
+        Expression::TSAsExpression { expression, .. }
+        | Expression::TSSatisfiesExpression { expression, .. }
+        | Expression::TSNonNullExpression { expression, .. }
+        | Expression::TSTypeAssertion { expression, .. } => is_simple_target(expression),
+        _ => false,
+    }
+}
+
+/// Fora do cabeçalho de um for-in/for-of e de um `declare`, `const` e
+/// desestruturações precisam de um valor inicial: `const a;` e `let [b];` não são válidos.
+fn check_initializers(declaration: &VariableDeclaration) -> Result<(), ParseError> {
+    for declarator in declaration.declarations.iter().filter(|d| d.init.is_none()) {
+        let message = match declarator.id {
+            Pattern::Identifier(_) if declaration.kind != "const" => continue,
+            Pattern::Identifier(_) => "Uma declaração 'const' precisa de um valor inicial",
+            _ => "Uma desestruturação precisa de um valor inicial",
+        };
+        return Err(ParseError::new(ErrorCode::MissingInitializer, declarator.span, message));
+    }
+    Ok(())
+}
+
+/// `{ a = 1 }` em um objeto literal que não virou desestruturação.
+fn cover_initializer_error(span: Span) -> ParseError {
+    ParseError::new(
+        ErrorCode::ExpectedToken,
+        span,
+        "'=' só pode seguir o nome de uma propriedade quando o objeto é uma desestruturação",
+    )
+}
+
+/// Array ou objeto literal que começa em `start`, sem parênteses em volta:
+/// `([a]) = x` não é uma desestruturação.
+fn is_unparenthesized_literal(expression: &Expression, start: Span) -> bool {
+    matches!(expression, Expression::ObjectExpression { .. } | Expression::ArrayExpression { .. })
+        && expression.span().start == start.start
+}
+
+/// Força dos operadores relacionais, também usada por `as` e `satisfies`.
+const RELATIONAL_POWER: u8 = 14;
+
+/// Força de ligação (esquerda, direita) dos operadores binários. Operadores associativos
+/// à esquerda têm a direita mais forte; `**` associa à direita. Os operandos de `??`
+/// são expressões bit a bit: `a ?? b || c` chega ao laço como `(a ?? b) || c`, onde
+/// a mistura sem parênteses é rejeitada.
+fn binary_binding_power(kind: TokenKind) -> Option<(u8, u8)> {
+    let power = match kind {
+        TokenKind::QuestionQuestion => return Some((2, 6)),
+        TokenKind::PipePipe => 2,
+        TokenKind::AmpAmp => 4,
+        TokenKind::Pipe => 6,
+        TokenKind::Caret => 8,
+        TokenKind::Amp => 10,
+        TokenKind::EqEq | TokenKind::NotEq | TokenKind::EqEqEq | TokenKind::NotEqEq => 12,
+        TokenKind::Lt
+        | TokenKind::Gt
+        | TokenKind::LtEq
+        | TokenKind::GtEq
+        | TokenKind::Instanceof
+        | TokenKind::In => RELATIONAL_POWER,
+        TokenKind::ShiftLeft | TokenKind::ShiftRight | TokenKind::UnsignedShiftRight => 16,
+        TokenKind::Plus | TokenKind::Minus => 18,
+        TokenKind::Star | TokenKind::Slash | TokenKind::Percent => 20,
+        TokenKind::StarStar => return Some((22, 22)),
+        _ => return None,
+    };
+    Some((power, power + 1))
+}
+
+const ASSIGNMENT_OPERATORS: &[TokenKind] = &[
+    TokenKind::Eq,
+    TokenKind::PlusEq,
+    TokenKind::MinusEq,
+    TokenKind::StarEq,
+    TokenKind::SlashEq,
+    TokenKind::PercentEq,
+    TokenKind::StarStarEq,
+    TokenKind::ShiftLeftEq,
+    TokenKind::ShiftRightEq,
+    TokenKind::UnsignedShiftRightEq,
+    TokenKind::AmpEq,
+    TokenKind::PipeEq,
+    TokenKind::CaretEq,
+    TokenKind::AmpAmpEq,
+    TokenKind::PipePipeEq,
+    TokenKind::QuestionQuestionEq,
+];
+
+/// Onde o parser está, para validar `break`, `continue` e rótulos.
+/// É reiniciado ao entrar no corpo de uma função.
+#[derive(Default)]
+struct StatementContext {
+    in_iteration: bool,
+    in_switch: bool,
+    // Rótulos ativos e se cada um marca um laço (alvo válido para `continue`)
+    labels: Vec<(String, bool)>,
+    // Dentro de uma função `async` (onde `await` é operador) ou de um generator (`yield`)
+    in_async: bool,
+    in_generator: bool,
 }
 
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Self {
         // Filtramos tokens que não afetam a sintaxe (espaços, comentários, quebras de linha)
-        // Isso simplifica muito a lógica do parser, pois não precisamos ficar pulando eles manualmente toda hora
+        // Isso simplifica muito a lógica do parser, pois não precisamos ficar pulando eles manualmente toda hora.
+        // Das quebras de linha só guardamos onde elas aconteceram, para a inserção automática de `;`.
+        let mut newline_before = Vec::new();
+        let mut pending_newline = false;
         let tokens = tokens
             .into_iter()
-            .filter(|t| !matches!(t.token_type, TokenType::Whitespace | TokenType::Comment | TokenType::Newline))
+            .filter(|t| {
+                if !t.kind.is_trivia() {
+                    newline_before.push(std::mem::take(&mut pending_newline));
+                    return true;
+                }
+                if t.kind == TokenKind::Newline || (t.kind == TokenKind::Comment && t.value.contains(['\n', '\r'])) {
+                    pending_newline = true;
+                }
+                false
+            })
             .collect();
         
-        Self { tokens, current: 0 }
+        Self {
+            tokens,
+            newline_before,
+            current: 0,
+            // Módulos aceitam `await` no nível superior
+            context: StatementContext {
+                in_async: true,
+                ..Default::default()
+            },
+            in_conditional_extends: false,
+            split_tokens: Vec::new(),
+            allow_in: true,
+            last_token: Span::default(),
+            expected_tokens: ExpectedTokens::default(),
+            errors: Vec::new(),
+            cover_initializer: None,
+            spreads_before_comma: HashSet::new(),
+            depth: 0,
+            in_conditional_consequent: false,
+        }
     }
 
-    pub fn parse(&mut self) -> Result<Program, ParseError> {
+    /// Analisa o arquivo inteiro. Sempre devolve um `Program`: trechos com erro
+    /// viram nós `Error`, e os erros encontrados vêm na lista, em ordem.
+    pub fn parse(&mut self) -> (Program, Vec<ParseError>) {
+        let start = self.start_span();
         let mut body = Vec::new();
 
         while !self.is_at_end() {
-            let stmt = self.parse_statement()?;
-            body.push(stmt);
+            body.push(self.parse_or_recover(Self::parse_module_item));
         }
 
-        Ok(Program { body })
+        let program = Program {
+            body,
+            span: self.finish_span(start),
+        };
+        (program, std::mem::take(&mut self.errors))
     }
 
     fn parse_statement(&mut self) -> Result<Statement, ParseError> {
-        if self.match_keyword("function") {
-            return self.parse_function_declaration();
-        }
-        if self.match_keyword("const") || self.match_keyword("let") || self.match_keyword("var") {
-            return self.parse_variable_declaration();
-        }
-        if self.match_keyword("return") {
-            return self.parse_return_statement();
-        }
-        if self.check(TokenType::Punctuation) && self.peek().unwrap().value == "{" {
-            return Ok(Statement::BlockStatement(self.parse_block_statement()?));
-        }
-
-        self.parse_expression_statement()
-    }
-
-    fn parse_function_declaration(&mut self) -> Result<Statement, ParseError> {
-        let id = self.parse_identifier()?;
-        
-        self.consume(TokenType::Punctuation, "(")?;
-        let mut params = Vec::new();
-        if !self.check_value(")") {
-            loop {
-                let param = self.parse_identifier()?;
-                params.push(param);
-                // TODO: Implementar análise de tipos completa.
-                // Por enquanto, apenas consumimos a anotação de tipo para não quebrar o parser.
-                if self.match_operator(":") {
-                    self.consume_type_annotation();
-                }
-                
-                if !self.match_punctuation(",") {
-                    break;
+        let start = self.start_span();
+        match self.peek_kind() {
+            Some(TokenKind::Function) => {
+                self.advance();
+                self.parse_function_declaration(start, true, false)
+            }
+            Some(TokenKind::Identifier)
+                if self.check_contextual("async")
+                    && self.peek_next_kind() == Some(TokenKind::Function)
+                    && !self.has_newline_after() =>
+            {
+                self.advance();
+                self.advance();
+                self.parse_function_declaration(start, true, true)
+            }
+            Some(TokenKind::Const) if self.peek_next_kind() == Some(TokenKind::Enum) => {
+                self.advance();
+                self.advance();
+                self.parse_enum_declaration(start, true)
+            }
+            Some(TokenKind::Const | TokenKind::Let | TokenKind::Var) => {
+                self.advance();
+                let mut declaration = self.parse_variable_declaration(start)?;
+                check_initializers(&declaration)?;
+                self.consume_semicolon()?;
+                declaration.span = self.finish_span(start);
+                Ok(Statement::VariableDeclaration(declaration))
+            }
+            Some(TokenKind::Declare)
+                if matches!(self.peek_next_kind(), Some(TokenKind::Const | TokenKind::Let | TokenKind::Var))
+                    && !self.has_newline_after() =>
+            {
+                self.advance();
+                self.advance();
+                let mut declaration = self.parse_variable_declaration(start)?;
+                declaration.declare = true;
+                self.consume_semicolon()?;
+                declaration.span = self.finish_span(start);
+                Ok(Statement::VariableDeclaration(declaration))
+            }
+            Some(TokenKind::Return) => {
+                self.advance();
+                self.parse_return_statement(start)
+            }
+            Some(TokenKind::LBrace) => Ok(Statement::BlockStatement(self.parse_block_statement()?)),
+            Some(TokenKind::If) => {
+                self.advance();
+                self.parse_if_statement(start)
+            }
+            Some(TokenKind::For) => {
+                self.advance();
+                self.parse_for_statement(start)
+            }
+            Some(TokenKind::While) => {
+                self.advance();
+                self.parse_while_statement(start)
+            }
+            Some(TokenKind::Do) => {
+                self.advance();
+                self.parse_do_while_statement(start)
+            }
+            Some(TokenKind::Switch) => {
+                self.advance();
+                self.parse_switch_statement(start)
+            }
+            Some(TokenKind::Try) => {
+                self.advance();
+                self.parse_try_statement(start)
+            }
+            Some(TokenKind::Throw) => {
+                self.advance();
+                if self.has_newline_before() {
+                    return Err(ParseError::new(
+                        ErrorCode::LineBreakNotAllowed,
+                        self.current_span(),
+                        "Não pode haver quebra de linha entre 'throw' e a expressão",
+                    ));
                 }
+                let argument = self.parse_expression()?;
+                self.consume_semicolon()?;
+                Ok(Statement::ThrowStatement {
+                    argument,
+                    span: self.finish_span(start),
+                })
+            }
+            Some(TokenKind::Break | TokenKind::Continue) => {
+                self.advance();
+                self.parse_break_continue_statement(start)
             }
+            Some(TokenKind::Semicolon) => {
+                self.advance();
+                Ok(Statement::EmptyStatement {
+                    span: self.finish_span(start),
+                })
+            }
+            Some(TokenKind::Debugger) => {
+                self.advance();
+                self.consume_semicolon()?;
+                Ok(Statement::DebuggerStatement {
+                    span: self.finish_span(start),
+                })
+            }
+            Some(TokenKind::Class | TokenKind::At) => {
+                Ok(Statement::ClassDeclaration(self.parse_class_declaration(start, Vec::new(), true)?))
+            }
+            // Com uma quebra de linha depois, `abstract` e `type` são identificadores
+            // seguidos de `;` automático (`type\nFoo = 1`)
+            Some(TokenKind::Abstract)
+                if self.peek_next_kind() == Some(TokenKind::Class) && !self.has_newline_after() =>
+            {
+                Ok(Statement::ClassDeclaration(self.parse_class_declaration(start, Vec::new(), true)?))
+            }
+            Some(TokenKind::Interface) if self.peek_next_kind().is_some_and(TokenKind::can_be_identifier) => {
+                self.advance();
+                self.parse_interface_declaration(start)
+            }
+            Some(TokenKind::Type)
+                if self.peek_next_kind().is_some_and(TokenKind::can_be_identifier) && !self.has_newline_after() =>
+            {
+                self.advance();
+                self.parse_type_alias_declaration(start)
+            }
+            Some(TokenKind::Enum) => {
+                self.advance();
+                self.parse_enum_declaration(start, false)
+            }
+            Some(kind) if kind.can_be_identifier() && self.peek_next_kind() == Some(TokenKind::Colon) => {
+                self.parse_labeled_statement()
+            }
+            Some(TokenKind::Import) if !matches!(self.peek_next_kind(), Some(TokenKind::LParen | TokenKind::Dot)) => {
+                Err(ParseError::new(
+                    ErrorCode::ModuleDeclarationNotTopLevel,
+                    self.current_span(),
+                    "Declarações `import` só podem aparecer no nível superior do módulo",
+                ))
+            }
+            Some(TokenKind::Export) => Err(ParseError::new(
+                ErrorCode::ModuleDeclarationNotTopLevel,
+                self.current_span(),
+                "Declarações `export` só podem aparecer no nível superior do módulo",
+            )),
+            _ => self.parse_expression_statement(),
         }
-        self.consume(TokenType::Punctuation, ")")?;
+    }
 
-        let mut return_type = None;
-        if self.match_operator(":") {
-            return_type = Some(self.consume_type_annotation());
-        }
+    /// Lê uma função depois de `function` (ou `async function`), com `start` no começo da declaração.
+    fn parse_function_declaration(
+        &mut self,
+        start: Span,
+        require_id: bool,
+        is_async: bool,
+    ) -> Result<Statement, ParseError> {
+        let generator = self.match_token(TokenKind::Star);
+        let id = if self.check_identifier() || require_id {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+        let type_parameters = self.parse_optional_type_parameters()?;
+        let params = self.parse_params(false, false)?;
 
-        let body = self.parse_block_statement()?;
+        let return_type = self.parse_optional_return_type()?;
+        let body = self.parse_function_body(is_async, generator)?;
 
         Ok(Statement::FunctionDeclaration {
             id,
+            type_parameters,
             params,
             return_type,
             body,
+            r#async: is_async,
+            generator,
+            span: self.finish_span(start),
         })
     }
 
-    fn parse_variable_declaration(&mut self) -> Result<Statement, ParseError> {
+    /// Lê `(a, b?: T, ...)`. Com `allow_properties`, aceita as propriedades de
+    /// parâmetro do construtor (`constructor(private readonly x: T)`); com
+    /// `allow_decorators`, os decoradores de parâmetro de construtores e métodos de classe.
+    fn parse_params(&mut self, allow_properties: bool, allow_decorators: bool) -> Result<Vec<Param>, ParseError> {
+        self.consume(TokenKind::LParen)?;
+        let mut params = Vec::new();
+        while !self.check(TokenKind::RParen) {
+            let start = self.start_span();
+            let decorators = self.parse_decorators()?;
+            if !allow_decorators {
+                self.reject_decorators(&decorators, "parâmetros fora de construtores e métodos de classe")?;
+            }
+            let modifiers = self.parse_modifiers()?;
+            if !modifiers.is_empty() {
+                if !allow_properties {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidParameterProperty,
+                        self.finish_span(start),
+                        "Propriedades de parâmetro só são permitidas na implementação de um construtor",
+                    ));
+                }
+                if modifiers.r#static || modifiers.r#abstract || modifiers.declare {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidParameterProperty,
+                        self.finish_span(start),
+                        "Apenas 'public', 'private', 'protected', 'readonly' e 'override' podem aparecer em um parâmetro",
+                    ));
+                }
+            }
+
+            let id_start = self.start_span();
+            let is_rest = self.match_token(TokenKind::Ellipsis);
+            // `this: T` declara o tipo do `this` dentro da função
+            let mut id = if modifiers.is_empty() && !is_rest && self.check(TokenKind::This) {
+                let name = self.advance().value.clone();
+                Pattern::Identifier(Identifier {
+                    name,
+                    span: self.last_token,
+                })
+            } else {
+                self.parse_binding_pattern()?
+            };
+            if !modifiers.is_empty() && !matches!(id, Pattern::Identifier(_)) {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidParameterProperty,
+                    self.finish_span(start),
+                    "Uma propriedade de parâmetro não pode ser uma desestruturação",
+                ));
+            }
+            let optional = self.match_token(TokenKind::Question);
+            let type_annotation = if self.match_token(TokenKind::Colon) {
+                Some(self.parse_type_annotation()?)
+            } else {
+                None
+            };
+            if is_rest {
+                if optional || self.check(TokenKind::Eq) {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidRestElement,
+                        self.finish_span(start),
+                        "Um parâmetro rest não pode ser opcional nem ter valor padrão",
+                    ));
+                }
+                if !self.check(TokenKind::RParen) {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidRestElement,
+                        self.finish_span(start),
+                        "Um parâmetro rest deve ser o último da lista",
+                    ));
+                }
+                id = Pattern::RestElement {
+                    argument: Box::new(id),
+                    span: self.finish_span(id_start),
+                };
+            } else {
+                id = self.parse_pattern_default(id_start, id)?;
+            }
+            params.push(Param {
+                decorators,
+                id,
+                optional,
+                type_annotation,
+                parameter_property: (!modifiers.is_empty()).then_some(ParameterProperty {
+                    accessibility: modifiers.accessibility,
+                    readonly: modifiers.readonly,
+                    r#override: modifiers.r#override,
+                }),
+                span: self.finish_span(start),
+            });
+
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+        }
+        self.consume(TokenKind::RParen)?;
+        Ok(params)
+    }
+
+    /// Rótulos e laços de fora não valem dentro de uma função, e `await`/`yield`
+    /// passam a depender só da própria função.
+    fn parse_function_body(&mut self, is_async: bool, is_generator: bool) -> Result<BlockStatement, ParseError> {
+        let outer = self.enter_function_context(is_async, is_generator);
+        let body = self.with_allow_in(true, |parser| parser.parse_block_statement());
+        self.context = outer;
+        body
+    }
+
+    fn enter_function_context(&mut self, is_async: bool, is_generator: bool) -> StatementContext {
+        std::mem::replace(
+            &mut self.context,
+            StatementContext {
+                in_async: is_async,
+                in_generator: is_generator,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Lê a lista de declaradores depois de `const`/`let`/`var`, sem o `;` final,
+    /// para ser usada tanto em declarações quanto no cabeçalho de um `for`.
+    fn parse_variable_declaration(&mut self, start: Span) -> Result<VariableDeclaration, ParseError> {
         let kind = self.previous().value.clone();
         let mut declarations = Vec::new();
 
         loop {
-            let id = self.parse_identifier()?;
+            let declarator_start = self.start_span();
+            let id = self.parse_binding_pattern()?;
+            let definite = matches!(id, Pattern::Identifier(_)) && self.match_token(TokenKind::Bang);
+            let type_annotation = if self.match_token(TokenKind::Colon) {
+                Some(self.parse_type_annotation()?)
+            } else {
+                None
+            };
             let mut init = None;
 
-            if self.match_operator("=") {
-                init = Some(self.parse_expression()?);
+            if self.match_token(TokenKind::Eq) {
+                init = Some(self.parse_assignment()?);
             }
 
-            declarations.push(VariableDeclarator { id, init });
+            declarations.push(VariableDeclarator {
+                id,
+                definite,
+                type_annotation,
+                init,
+                span: self.finish_span(declarator_start),
+            });
 
-            if !self.match_punctuation(",") {
+            if !self.match_token(TokenKind::Comma) {
                 break;
             }
         }
 
-        self.consume(TokenType::Punctuation, ";")?;
-
-        Ok(Statement::VariableDeclaration { kind, declarations })
+        Ok(VariableDeclaration {
+            kind,
+            declare: false,
+            declarations,
+            span: self.finish_span(start),
+        })
     }
 
-    fn parse_return_statement(&mut self) -> Result<Statement, ParseError> {
+    fn parse_return_statement(&mut self, start: Span) -> Result<Statement, ParseError> {
         let mut argument = None;
-        if !self.check(TokenType::Punctuation) || self.peek().unwrap().value != ";" {
+        if !self.check(TokenKind::Semicolon) && !self.can_insert_semicolon() {
             argument = Some(self.parse_expression()?);
         }
-        self.consume(TokenType::Punctuation, ";")?;
-        Ok(Statement::ReturnStatement { argument })
-    }
-
-    fn parse_block_statement(&mut self) -> Result<BlockStatement, ParseError> {
-        self.consume(TokenType::Punctuation, "{")?;
-        let mut body = Vec::new();
-        while !self.check_value("}") && !self.is_at_end() {
-            let stmt = self.parse_statement()?;
-            body.push(stmt);
-        }
-        self.consume(TokenType::Punctuation, "}")?;
-        Ok(BlockStatement { body })
-    }
-
-    fn parse_expression_statement(&mut self) -> Result<Statement, ParseError> {
-        let expression = self.parse_expression()?;
-        self.consume(TokenType::Punctuation, ";")?;
-        Ok(Statement::ExpressionStatement { expression })
+        self.consume_semicolon()?;
+        Ok(Statement::ReturnStatement {
+            argument,
+            span: self.finish_span(start),
+        })
     }
 
-    fn parse_expression(&mut self) -> Result<Expression, ParseError> {
-        self.parse_logical_or()
+    fn parse_if_statement(&mut self, start: Span) -> Result<Statement, ParseError> {
+        self.consume(TokenKind::LParen)?;
+        let test = self.parse_expression()?;
+        self.consume(TokenKind::RParen)?;
+        let consequent = Box::new(self.parse_substatement()?);
+        // O `else` pendente fica com o `if` mais próximo
+        let alternate = if self.match_token(TokenKind::Else) {
+            Some(Box::new(self.parse_substatement()?))
+        } else {
+            None
+        };
+        Ok(Statement::IfStatement {
+            test,
+            consequent,
+            alternate,
+            span: self.finish_span(start),
+        })
     }
 
-    fn parse_logical_or(&mut self) -> Result<Expression, ParseError> {
-        let mut left = self.parse_logical_and()?;
-
-        while self.match_operator("||") {
-            let operator = self.previous().value.clone();
-            let right = self.parse_logical_and()?;
-            left = Expression::BinaryExpression {
-                operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
+    fn parse_for_statement(&mut self, start: Span) -> Result<Statement, ParseError> {
+        let is_await = self.context.in_async && self.check_contextual("await");
+        if is_await {
+            self.advance();
         }
+        self.consume(TokenKind::LParen)?;
+
+        let init_start = self.start_span();
+        let init = self.with_allow_in(false, |parser| {
+            Ok(if parser.check(TokenKind::Semicolon) {
+                None
+            } else if parser.match_token(TokenKind::Const)
+                || parser.match_token(TokenKind::Let)
+                || parser.match_token(TokenKind::Var)
+            {
+                Some(ForInit::VariableDeclaration(parser.parse_variable_declaration(init_start)?))
+            } else {
+                // `for ({ a = 1 } of xs)`: o literal ainda pode virar desestruturação
+                let first = parser.parse_assignment_or_cover(true)?;
+                Some(ForInit::Expression(parser.parse_sequence_rest(init_start, first)?))
+            })
+        })?;
+        let cover_initializer = self.cover_initializer.take();
 
-        Ok(left)
+        if let Some(left) = init {
+            let is_of = self.check(TokenKind::Of);
+            if is_await && !is_of {
+                return Err(self.expected_token(TokenKind::Of));
+            }
+            if is_of || self.check(TokenKind::In) {
+                self.advance();
+                let left = match left {
+                    ForInit::VariableDeclaration(declaration) => {
+                        if declaration.declarations.len() != 1 {
+                            return Err(ParseError::new(
+                                ErrorCode::InvalidForHead,
+                                declaration.span,
+                                "O cabeçalho de um for-in/for-of deve declarar uma única variável",
+                            ));
+                        }
+                        if let Some(init) = &declaration.declarations[0].init {
+                            return Err(ParseError::new(
+                                ErrorCode::InvalidForHead,
+                                init.span(),
+                                "A variável de um for-in/for-of não pode ter inicializador",
+                            ));
+                        }
+                        ForInit::VariableDeclaration(declaration)
+                    }
+                    // Como em `[a, b] = c`: um literal vira desestruturação, o resto precisa ser um alvo simples
+                    ForInit::Expression(expression) if is_unparenthesized_literal(&expression, init_start) => {
+                        ForInit::Pattern(self.expression_to_pattern(expression)?)
+                    }
+                    ForInit::Expression(expression) => ForInit::Pattern(self.expression_to_simple_target(expression)?),
+                    ForInit::Pattern(pattern) => ForInit::Pattern(pattern),
+                };
+                // `for (x of a, b)` não é válido; no for-in a vírgula é permitida
+                let right = if is_of {
+                    self.parse_assignment()?
+                } else {
+                    self.parse_expression()?
+                };
+                self.consume(TokenKind::RParen)?;
+                let body = Box::new(self.parse_loop_body()?);
+                return Ok(if is_of {
+                    Statement::ForOfStatement {
+                        left,
+                        right,
+                        body,
+                        r#await: is_await,
+                        span: self.finish_span(start),
+                    }
+                } else {
+                    Statement::ForInStatement {
+                        left,
+                        right,
+                        body,
+                        span: self.finish_span(start),
+                    }
+                });
+            }
+            if is_await {
+                return Err(self.expected_token(TokenKind::Of));
+            }
+            if let Some(span) = cover_initializer {
+                return Err(cover_initializer_error(span));
+            }
+            if let ForInit::VariableDeclaration(declaration) = &left {
+                check_initializers(declaration)?;
+            }
+            return self.finish_for_statement(start, Some(left));
+        }
+
+        if is_await {
+            return Err(ParseError::new(
+                ErrorCode::InvalidForHead,
+                self.finish_span(start),
+                "`for await` exige um laço for-of",
+            ));
+        }
+        self.finish_for_statement(start, None)
+    }
+
+    fn finish_for_statement(&mut self, start: Span, init: Option<ForInit>) -> Result<Statement, ParseError> {
+        self.consume(TokenKind::Semicolon)?;
+        let test = if self.check(TokenKind::Semicolon) {
+            None
+        } else {
+            Some(self.parse_expression()?)
+        };
+        self.consume(TokenKind::Semicolon)?;
+        let update = if self.check(TokenKind::RParen) {
+            None
+        } else {
+            Some(self.parse_expression()?)
+        };
+        self.consume(TokenKind::RParen)?;
+        let body = Box::new(self.parse_loop_body()?);
+        Ok(Statement::ForStatement {
+            init,
+            test,
+            update,
+            body,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_while_statement(&mut self, start: Span) -> Result<Statement, ParseError> {
+        self.consume(TokenKind::LParen)?;
+        let test = self.parse_expression()?;
+        self.consume(TokenKind::RParen)?;
+        let body = Box::new(self.parse_loop_body()?);
+        Ok(Statement::WhileStatement {
+            test,
+            body,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_do_while_statement(&mut self, start: Span) -> Result<Statement, ParseError> {
+        let body = Box::new(self.parse_loop_body()?);
+        self.consume(TokenKind::While)?;
+        self.consume(TokenKind::LParen)?;
+        let test = self.parse_expression()?;
+        self.consume(TokenKind::RParen)?;
+        // O `;` depois de `do ... while (x)` é opcional
+        self.match_token(TokenKind::Semicolon);
+        Ok(Statement::DoWhileStatement {
+            body,
+            test,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Instrução sozinha no corpo de um `if`, de um laço ou de um rótulo, onde só
+    /// `var` pode declarar algo: `if (a) let x = 1;` não é válido.
+    fn parse_substatement(&mut self) -> Result<Statement, ParseError> {
+        let start = self.start_span();
+        let statement = self.parse_statement()?;
+        let is_declaration = match &statement {
+            Statement::VariableDeclaration(declaration) => declaration.kind != "var",
+            Statement::FunctionDeclaration { .. }
+            | Statement::ClassDeclaration(_)
+            | Statement::TSInterfaceDeclaration { .. }
+            | Statement::TSTypeAliasDeclaration { .. }
+            | Statement::TSEnumDeclaration { .. } => true,
+            _ => false,
+        };
+        if is_declaration {
+            return Err(ParseError::new(
+                ErrorCode::DeclarationNotAllowed,
+                self.finish_span(start),
+                "Uma declaração não pode ser o corpo de um `if`, de um laço ou de um rótulo",
+            ));
+        }
+        Ok(statement)
+    }
+
+    /// Corpo de um laço: dentro dele `break` e `continue` são válidos.
+    fn parse_loop_body(&mut self) -> Result<Statement, ParseError> {
+        let was_in_iteration = std::mem::replace(&mut self.context.in_iteration, true);
+        let body = self.parse_substatement();
+        self.context.in_iteration = was_in_iteration;
+        body
+    }
+
+    fn parse_switch_statement(&mut self, start: Span) -> Result<Statement, ParseError> {
+        self.consume(TokenKind::LParen)?;
+        let discriminant = self.parse_expression()?;
+        self.consume(TokenKind::RParen)?;
+        self.consume(TokenKind::LBrace)?;
+
+        let was_in_switch = std::mem::replace(&mut self.context.in_switch, true);
+        let cases = self.parse_switch_cases();
+        self.context.in_switch = was_in_switch;
+        let cases = cases?;
+
+        self.consume(TokenKind::RBrace)?;
+        Ok(Statement::SwitchStatement {
+            discriminant,
+            cases,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_switch_cases(&mut self) -> Result<Vec<SwitchCase>, ParseError> {
+        let mut cases = Vec::new();
+        let mut has_default = false;
+
+        while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+            let start = self.start_span();
+            let test = if self.match_token(TokenKind::Case) {
+                Some(self.parse_expression()?)
+            } else {
+                self.consume(TokenKind::Default)?;
+                if has_default {
+                    self.errors.push(ParseError::new(
+                        ErrorCode::DuplicateDefaultClause,
+                        self.last_token,
+                        "Um 'switch' não pode ter mais de uma cláusula 'default'",
+                    ));
+                }
+                has_default = true;
+                None
+            };
+            self.consume(TokenKind::Colon)?;
+
+            let mut consequent = Vec::new();
+            while !matches!(self.peek_kind(), Some(TokenKind::Case | TokenKind::Default | TokenKind::RBrace) | None) {
+                consequent.push(self.parse_or_recover(Self::parse_statement));
+            }
+            cases.push(SwitchCase {
+                test,
+                consequent,
+                span: self.finish_span(start),
+            });
+        }
+
+        Ok(cases)
+    }
+
+    fn parse_try_statement(&mut self, start: Span) -> Result<Statement, ParseError> {
+        let block = self.parse_block_statement()?;
+
+        let catch_start = self.start_span();
+        let handler = if self.match_token(TokenKind::Catch) {
+            // `catch { }` sem parâmetro é permitido (optional catch binding)
+            let (param, type_annotation) = if self.match_token(TokenKind::LParen) {
+                let param = self.parse_binding_pattern()?;
+                let type_annotation = if self.match_token(TokenKind::Colon) {
+                    Some(self.parse_type_annotation()?)
+                } else {
+                    None
+                };
+                self.consume(TokenKind::RParen)?;
+                (Some(param), type_annotation)
+            } else {
+                (None, None)
+            };
+            let body = self.parse_block_statement()?;
+            Some(CatchClause {
+                param,
+                type_annotation,
+                body,
+                span: self.finish_span(catch_start),
+            })
+        } else {
+            None
+        };
+
+        let finalizer = if self.match_token(TokenKind::Finally) {
+            Some(self.parse_block_statement()?)
+        } else {
+            None
+        };
+
+        if handler.is_none() && finalizer.is_none() {
+            return Err(self.expected_token(TokenKind::Catch));
+        }
+
+        Ok(Statement::TryStatement {
+            block,
+            handler,
+            finalizer,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_break_continue_statement(&mut self, start: Span) -> Result<Statement, ParseError> {
+        let is_break = self.previous().kind == TokenKind::Break;
+
+        let label = if self.check_identifier() && !self.has_newline_before() {
+            let label = self.parse_identifier()?;
+            // Erros semânticos: a instrução em si está bem formada e fica na árvore
+            match self.context.labels.iter().find(|(name, _)| *name == label.name) {
+                None => {
+                    self.errors.push(ParseError::new(
+                        ErrorCode::UndefinedLabel,
+                        label.span,
+                        format!("Rótulo '{}' não encontrado", label.name),
+                    ));
+                }
+                Some((_, false)) if !is_break => {
+                    self.errors.push(ParseError::new(
+                        ErrorCode::InvalidJump,
+                        label.span,
+                        format!("'continue' só pode usar rótulos de laços, e '{}' não é um", label.name),
+                    ));
+                }
+                _ => {}
+            }
+            Some(label)
+        } else {
+            let allowed = if is_break {
+                self.context.in_iteration || self.context.in_switch
+            } else {
+                self.context.in_iteration
+            };
+            if !allowed {
+                let message = if is_break {
+                    "'break' só pode ser usado dentro de um laço ou 'switch'"
+                } else {
+                    "'continue' só pode ser usado dentro de um laço"
+                };
+                self.errors.push(ParseError::new(ErrorCode::InvalidJump, self.finish_span(start), message));
+            }
+            None
+        };
+        self.consume_semicolon()?;
+
+        let span = self.finish_span(start);
+        Ok(if is_break {
+            Statement::BreakStatement { label, span }
+        } else {
+            Statement::ContinueStatement { label, span }
+        })
+    }
+
+    fn parse_labeled_statement(&mut self) -> Result<Statement, ParseError> {
+        let start = self.start_span();
+        let label = self.parse_identifier()?;
+        self.consume(TokenKind::Colon)?;
+
+        if self.context.labels.iter().any(|(name, _)| *name == label.name) {
+            self.errors.push(ParseError::new(
+                ErrorCode::DuplicateLabel,
+                label.span,
+                format!("Rótulo '{}' já declarado", label.name),
+            ));
+        }
+        let is_loop = matches!(
+            self.peek_kind(),
+            Some(TokenKind::For | TokenKind::While | TokenKind::Do)
+        );
+        self.context.labels.push((label.name.clone(), is_loop));
+        let body = self.parse_substatement();
+        self.context.labels.pop();
+
+        Ok(Statement::LabeledStatement {
+            label,
+            body: Box::new(body?),
+            span: self.finish_span(start),
+        })
+    }
+
+    /// `[@dec...] [abstract] class ...` em uma declaração. `decorators` são os que
+    /// vieram antes de um `export` (`@dec export class A {}`); os de depois
+    /// (`export @dec class A {}`) são lidos aqui, mas não pode haver dos dois.
+    fn parse_class_declaration(
+        &mut self,
+        start: Span,
+        mut decorators: Vec<Decorator>,
+        require_id: bool,
+    ) -> Result<Class, ParseError> {
+        if self.check(TokenKind::At) {
+            if !decorators.is_empty() {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidDecorator,
+                    self.current_span(),
+                    "Decoradores não podem aparecer antes e depois de 'export' ao mesmo tempo",
+                ));
+            }
+            decorators = self.parse_decorators()?;
+        }
+        let is_abstract = self.match_token(TokenKind::Abstract);
+        if !self.match_token(TokenKind::Class) {
+            if decorators.is_empty() {
+                return Err(self.expected_token(TokenKind::Class));
+            }
+            return Err(self.unexpected(
+                ErrorCode::InvalidDecorator,
+                "Decoradores só podem ser usados em classes, em seus membros e em parâmetros: 'class' esperado",
+            ));
+        }
+        self.parse_class(start, is_abstract, require_id, decorators)
+    }
+
+    fn parse_decorators(&mut self) -> Result<Vec<Decorator>, ParseError> {
+        let mut decorators = Vec::new();
+        while self.check(TokenKind::At) {
+            decorators.push(self.parse_decorator()?);
+        }
+        Ok(decorators)
+    }
+
+    /// `@nome`, `@a.b`, `@a.b(...)` ou `@(expressão)`. Sem parênteses, só nomes
+    /// separados por `.` e uma chamada no fim: em `@dec [chave]() {}` o `[` já é o membro.
+    fn parse_decorator(&mut self) -> Result<Decorator, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::At)?;
+        let expression = if self.match_token(TokenKind::LParen) {
+            let expression = self.with_allow_in(true, |parser| parser.parse_expression())?;
+            self.consume(TokenKind::RParen)?;
+            expression
+        } else {
+            let expression_start = self.start_span();
+            let mut expression = Expression::Identifier(self.parse_identifier()?);
+            while self.match_token(TokenKind::Dot) {
+                let property = self.parse_property_identifier()?;
+                expression = Expression::MemberExpression {
+                    object: Box::new(expression),
+                    property: Box::new(Expression::Identifier(property)),
+                    computed: false,
+                    optional: false,
+                    span: self.finish_span(expression_start),
+                };
+            }
+            let type_arguments = self.try_parse_call_type_arguments();
+            if self.match_token(TokenKind::LParen) {
+                expression = self.finish_call(expression_start, expression, type_arguments, false)?;
+            }
+            expression
+        };
+        Ok(Decorator {
+            expression,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Erro se `decorators` não estiver vazio, para os membros que não aceitam decoradores.
+    fn reject_decorators(&self, decorators: &[Decorator], member: &str) -> Result<(), ParseError> {
+        match decorators.first() {
+            Some(decorator) => Err(ParseError::new(
+                ErrorCode::InvalidDecorator,
+                decorator.span,
+                format!("Decoradores não podem ser usados em {}", member),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Lê uma classe depois da palavra `class`. Em expressões o nome é opcional.
+    fn parse_class(
+        &mut self,
+        start: Span,
+        is_abstract: bool,
+        require_id: bool,
+        decorators: Vec<Decorator>,
+    ) -> Result<Class, ParseError> {
+        let id = if self.check_identifier() || require_id {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
+
+        let type_parameters = self.parse_optional_type_parameters()?;
+
+        let (super_class, super_type_arguments) = if self.match_token(TokenKind::Extends) {
+            let super_class = self.parse_member_call_expression()?;
+            let type_arguments = if self.check(TokenKind::Lt) {
+                Some(self.parse_type_arguments()?)
+            } else {
+                None
+            };
+            (Some(Box::new(super_class)), type_arguments)
+        } else {
+            (None, None)
+        };
+
+        let implements = if self.match_token(TokenKind::Implements) {
+            self.parse_heritage_list()?
+        } else {
+            Vec::new()
+        };
+
+        self.consume(TokenKind::LBrace)?;
+        let mut body = Vec::new();
+        while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+            if let Some(member) = self.parse_class_member_or_recover(is_abstract) {
+                body.push(member);
+            }
+        }
+        self.consume(TokenKind::RBrace)?;
+
+        Ok(Class {
+            decorators,
+            id,
+            type_parameters,
+            super_class,
+            super_type_arguments,
+            implements,
+            r#abstract: is_abstract,
+            body,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_interface_declaration(&mut self, start: Span) -> Result<Statement, ParseError> {
+        let id = self.parse_identifier()?;
+        let type_parameters = self.parse_optional_type_parameters()?;
+
+        let extends = if self.match_token(TokenKind::Extends) {
+            self.parse_heritage_list()?
+        } else {
+            Vec::new()
+        };
+
+        let body = self.parse_type_members()?;
+        Ok(Statement::TSInterfaceDeclaration {
+            id,
+            type_parameters,
+            extends,
+            body,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// `A<T>, ns.B` depois de `extends` (interfaces) ou `implements` (classes).
+    fn parse_heritage_list(&mut self) -> Result<Vec<InterfaceHeritage>, ParseError> {
+        let mut heritage = Vec::new();
+        loop {
+            let start = self.start_span();
+            let expression = self.parse_entity_name()?;
+            let type_arguments = if self.check(TokenKind::Lt) {
+                Some(self.parse_type_arguments()?)
+            } else {
+                None
+            };
+            heritage.push(InterfaceHeritage {
+                expression,
+                type_arguments,
+                span: self.finish_span(start),
+            });
+            if !self.match_token(TokenKind::Comma) {
+                return Ok(heritage);
+            }
+        }
+    }
+
+    fn parse_type_alias_declaration(&mut self, start: Span) -> Result<Statement, ParseError> {
+        let id = self.parse_identifier()?;
+        let type_parameters = self.parse_optional_type_parameters()?;
+        self.consume(TokenKind::Eq)?;
+        let type_annotation = self.parse_type_annotation()?;
+        self.consume_semicolon()?;
+
+        Ok(Statement::TSTypeAliasDeclaration {
+            id,
+            type_parameters,
+            type_annotation,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Lê `enum E { A, B = 1, "c" = B << 1, ["d"] }` depois de `enum` (ou `const enum`).
+    fn parse_enum_declaration(&mut self, start: Span, is_const: bool) -> Result<Statement, ParseError> {
+        let id = self.parse_identifier()?;
+        self.consume(TokenKind::LBrace)?;
+
+        let mut members = Vec::new();
+        while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+            let member_start = self.start_span();
+            if matches!(
+                self.peek_kind(),
+                Some(TokenKind::NumericLiteral | TokenKind::BigIntLiteral | TokenKind::PrivateName)
+            ) {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidEnumMember,
+                    self.current_span(),
+                    "Membro de enum deve ter um nome identificador ou string",
+                ));
+            }
+            let (member_id, computed) = self.parse_property_name()?;
+            let initializer = if self.match_token(TokenKind::Eq) {
+                Some(self.parse_assignment()?)
+            } else {
+                None
+            };
+            members.push(EnumMember {
+                id: member_id,
+                computed,
+                initializer,
+                span: self.finish_span(member_start),
+            });
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+        }
+        self.consume(TokenKind::RBrace)?;
+
+        Ok(Statement::TSEnumDeclaration {
+            id,
+            r#const: is_const,
+            members,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Nome qualificado usado em `implements`: `A`, `ns.A`, `a.b.C`.
+    fn parse_entity_name(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        let mut name = Expression::Identifier(self.parse_identifier()?);
+        while self.match_token(TokenKind::Dot) {
+            let property = self.parse_property_identifier()?;
+            name = Expression::MemberExpression {
+                object: Box::new(name),
+                property: Box::new(Expression::Identifier(property)),
+                computed: false,
+                optional: false,
+                span: self.finish_span(start),
+            };
+        }
+        Ok(name)
+    }
+
+    fn parse_class_member(&mut self, in_abstract_class: bool) -> Result<Option<ClassMember>, ParseError> {
+        if self.match_token(TokenKind::Semicolon) {
+            return Ok(None);
+        }
+
+        let start = self.start_span();
+        let decorators = self.parse_decorators()?;
+        if self.check(TokenKind::Static) && self.peek_next_kind() == Some(TokenKind::LBrace) {
+            self.reject_decorators(&decorators, "blocos estáticos")?;
+            self.advance();
+            let block = self.parse_function_body(false, false)?;
+            return Ok(Some(ClassMember::StaticBlock {
+                body: block.body,
+                span: self.finish_span(start),
+            }));
+        }
+
+        let modifiers = self.parse_modifiers()?;
+        if modifiers.r#abstract && !in_abstract_class {
+            return Err(ParseError::new(
+                ErrorCode::InvalidModifier,
+                self.finish_span(start),
+                "Membros abstratos só podem aparecer em uma classe abstrata",
+            ));
+        }
+
+        // `[chave: string]: T` é uma assinatura de índice, não uma chave computada
+        if self.check(TokenKind::LBracket)
+            && self.peek_next_kind().is_some_and(|k| k == TokenKind::Identifier || k.is_keyword())
+            && self.tokens.get(self.current + 2).map(|t| t.kind) == Some(TokenKind::Colon)
+        {
+            self.reject_decorators(&decorators, "assinaturas de índice")?;
+            let (parameters, type_annotation) = self.parse_index_signature()?;
+            self.consume_semicolon()?;
+            return Ok(Some(ClassMember::TSIndexSignature {
+                parameters,
+                type_annotation: Some(type_annotation),
+                modifiers,
+                span: self.finish_span(start),
+            }));
+        }
+
+        let mut kind = "method";
+        let mut is_async = false;
+        if matches!(self.peek_kind(), Some(TokenKind::Get | TokenKind::Set)) && self.is_modifier_position() {
+            kind = if self.advance().kind == TokenKind::Get { "get" } else { "set" };
+        } else if self.check_contextual("async") && self.is_modifier_position() && !self.has_newline_after() {
+            self.advance();
+            is_async = true;
+        }
+        let generator = kind == "method" && self.match_token(TokenKind::Star);
+
+        let (key, computed) = self.parse_property_name()?;
+        if matches!(key, Expression::PrivateIdentifier { .. }) && modifiers.accessibility.is_some() {
+            return Err(ParseError::new(
+                ErrorCode::InvalidModifier,
+                key.span(),
+                "Modificadores de acessibilidade não podem ser usados com nomes privados",
+            ));
+        }
+        let optional = self.match_token(TokenKind::Question);
+
+        if self.check(TokenKind::LParen) || self.check(TokenKind::Lt) {
+            let is_constructor = !computed
+                && kind == "method"
+                && match &key {
+                    Expression::Identifier(id) => id.name == "constructor",
+                    Expression::Literal {
+                        value: LiteralValue::String(value),
+                        ..
+                    } => value == "constructor",
+                    _ => false,
+                };
+            if is_constructor {
+                kind = "constructor";
+                self.reject_decorators(&decorators, "construtores; decore a classe ou os parâmetros")?;
+                if modifiers.r#static || modifiers.r#abstract {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidConstructor,
+                        key.span(),
+                        "O construtor não pode ser 'static' nem 'abstract'",
+                    ));
+                }
+                if is_async || generator {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidConstructor,
+                        key.span(),
+                        "O construtor não pode ser 'async' nem um generator",
+                    ));
+                }
+            }
+
+            let function_start = self.start_span();
+            let type_parameters = self.parse_optional_type_parameters()?;
+            if type_parameters.is_some() && kind != "method" {
+                let (code, message) = if kind == "constructor" {
+                    (ErrorCode::InvalidConstructor, "O construtor não pode ter parâmetros de tipo")
+                } else {
+                    (ErrorCode::InvalidAccessor, "Um acessor não pode ter parâmetros de tipo")
+                };
+                return Err(ParseError::new(code, self.finish_span(function_start), message));
+            }
+            let params = self.parse_params(is_constructor, kind != "get" && kind != "set")?;
+            if kind == "get" && !params.is_empty() {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidAccessor,
+                    key.span(),
+                    "Um acessor 'get' não pode ter parâmetros",
+                ));
+            }
+            if kind == "set" && params.len() != 1 {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidAccessor,
+                    key.span(),
+                    "Um acessor 'set' deve ter exatamente um parâmetro",
+                ));
+            }
+            let return_type = self.parse_optional_return_type()?;
+
+            // Sem corpo: método abstrato ou assinatura de sobrecarga
+            let body = if !self.check(TokenKind::LBrace) {
+                self.consume_semicolon()?;
+                None
+            } else {
+                if modifiers.r#abstract {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidModifier,
+                        self.current_span(),
+                        "Um método abstrato não pode ter implementação",
+                    ));
+                }
+                Some(self.parse_function_body(is_async, generator)?)
+            };
+            if body.is_none() && params.iter().any(|p| p.parameter_property.is_some()) {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidParameterProperty,
+                    self.finish_span(function_start),
+                    "Propriedades de parâmetro só são permitidas na implementação de um construtor",
+                ));
+            }
+
+            return Ok(Some(ClassMember::MethodDefinition {
+                decorators,
+                key,
+                computed,
+                optional,
+                kind: kind.to_string(),
+                modifiers,
+                value: Function {
+                    type_parameters,
+                    params,
+                    return_type,
+                    body,
+                    r#async: is_async,
+                    generator,
+                    span: self.finish_span(function_start),
+                },
+                span: self.finish_span(start),
+            }));
+        }
+
+        if kind != "method" || is_async || generator {
+            return Err(self.expected_token(TokenKind::LParen));
+        }
+
+        let definite = !optional && self.match_token(TokenKind::Bang);
+        let type_annotation = if self.match_token(TokenKind::Colon) {
+            Some(self.parse_type_annotation()?)
+        } else {
+            None
+        };
+        let value = if self.match_token(TokenKind::Eq) {
+            if modifiers.r#abstract {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidModifier,
+                    self.last_token,
+                    "Uma propriedade abstrata não pode ter inicializador",
+                ));
+            }
+            Some(self.parse_assignment()?)
+        } else {
+            None
+        };
+        self.consume_semicolon()?;
+
+        Ok(Some(ClassMember::PropertyDefinition {
+            decorators,
+            key,
+            computed,
+            optional,
+            definite,
+            modifiers,
+            type_annotation,
+            value,
+            span: self.finish_span(start),
+        }))
+    }
+
+    /// Lê modificadores (`public`, `static`, `readonly`...) enquanto a palavra
+    /// estiver de fato modificando algo; em `static() {}` ou `readonly: T` ela é o nome.
+    fn parse_modifiers(&mut self) -> Result<Modifiers, ParseError> {
+        let mut modifiers = Modifiers::default();
+
+        while let Some(token) = self.peek() {
+            let is_modifier = matches!(
+                token.kind,
+                TokenKind::Public
+                    | TokenKind::Private
+                    | TokenKind::Protected
+                    | TokenKind::Static
+                    | TokenKind::Readonly
+                    | TokenKind::Abstract
+                    | TokenKind::Declare
+            ) || self.check_contextual("override");
+            if !is_modifier || !self.is_modifier_position() {
+                break;
+            }
+
+            let token = self.advance();
+            let word = token.value.clone();
+            let repeated = match token.kind {
+                TokenKind::Public | TokenKind::Private | TokenKind::Protected => {
+                    modifiers.accessibility.replace(word.clone()).is_some()
+                }
+                TokenKind::Static => std::mem::replace(&mut modifiers.r#static, true),
+                TokenKind::Readonly => std::mem::replace(&mut modifiers.readonly, true),
+                TokenKind::Abstract => std::mem::replace(&mut modifiers.r#abstract, true),
+                TokenKind::Declare => std::mem::replace(&mut modifiers.declare, true),
+                _ => std::mem::replace(&mut modifiers.r#override, true),
+            };
+            if repeated {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidModifier,
+                    self.last_token,
+                    format!("Modificador '{}' repetido", word),
+                ));
+            }
+        }
+
+        Ok(modifiers)
+    }
+
+    /// Uma palavra como `static` ou `get` só é modificador se vier seguida de algo
+    /// que possa ser o nome de um membro.
+    fn is_modifier_position(&self) -> bool {
+        self.peek_next_kind().is_some_and(|k| {
+            k.is_keyword()
+                || matches!(
+                    k,
+                    TokenKind::Identifier
+                        | TokenKind::PrivateName
+                        | TokenKind::StringLiteral
+                        | TokenKind::NumericLiteral
+                        | TokenKind::LBracket
+                        | TokenKind::Star
+                )
+        })
+    }
+
+    /// Nome de membro: identificador (palavras-chave incluídas), string, número,
+    /// `#privado` ou `[expressão]`. Retorna também se a chave é computada.
+    fn parse_property_name(&mut self) -> Result<(Expression, bool), ParseError> {
+        match self.peek_kind() {
+            Some(TokenKind::LBracket) => {
+                self.advance();
+                let key = self.with_allow_in(true, |parser| parser.parse_assignment())?;
+                self.consume(TokenKind::RBracket)?;
+                Ok((key, true))
+            }
+            Some(TokenKind::StringLiteral | TokenKind::NumericLiteral | TokenKind::BigIntLiteral) => {
+                Ok((self.parse_literal(), false))
+            }
+            Some(TokenKind::PrivateName) => {
+                let name = self.advance().value[1..].to_string();
+                Ok((
+                    Expression::PrivateIdentifier {
+                        name,
+                        span: self.last_token,
+                    },
+                    false,
+                ))
+            }
+            _ => Ok((Expression::Identifier(self.parse_property_identifier()?), false)),
+        }
+    }
+
+    /// Em posição de nome de propriedade qualquer palavra-chave vale como identificador.
+    fn parse_property_identifier(&mut self) -> Result<Identifier, ParseError> {
+        if self.peek_kind().is_some_and(|k| k.is_keyword()) {
+            let name = self.advance().value.clone();
+            return Ok(Identifier {
+                name,
+                span: self.last_token,
+            });
+        }
+        self.parse_identifier()
+    }
+
+    fn parse_block_statement(&mut self) -> Result<BlockStatement, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::LBrace)?;
+        let mut body = Vec::new();
+        while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+            body.push(self.parse_or_recover(Self::parse_statement));
+        }
+        // Um bloco aberto até o fim do arquivo é mantido, com o erro registrado
+        if !self.match_token(TokenKind::RBrace) {
+            self.errors.push(self.expected_token(TokenKind::RBrace));
+        }
+        Ok(BlockStatement {
+            body,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_expression_statement(&mut self) -> Result<Statement, ParseError> {
+        let start = self.start_span();
+        let expression = self.parse_expression()?;
+        self.consume_semicolon()?;
+        Ok(Statement::ExpressionStatement {
+            expression,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Expressão completa, incluindo o operador vírgula (`a, b`).
+    fn parse_expression(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        let first = self.parse_assignment()?;
+        self.parse_sequence_rest(start, first)
+    }
+
+    /// Continua uma sequência `a, b` a partir da primeira expressão, já lida.
+    fn parse_sequence_rest(&mut self, start: Span, first: Expression) -> Result<Expression, ParseError> {
+        if !self.check(TokenKind::Comma) {
+            return Ok(first);
+        }
+
+        let mut expressions = vec![first];
+        while self.match_token(TokenKind::Comma) {
+            expressions.push(self.parse_assignment()?);
+        }
+        Ok(Expression::SequenceExpression {
+            expressions,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Expressão sem vírgula, usada em argumentos, inicializadores e valores de propriedades.
+    fn parse_assignment(&mut self) -> Result<Expression, ParseError> {
+        self.parse_assignment_or_cover(false)
+    }
+
+    /// Como `parse_assignment`, mas dentro de um array ou objeto literal (`allow_cover`)
+    /// aceita um `{ a = 1 }` ainda pendente, que só vale se o literal de fora virar uma
+    /// desestruturação. Fora disso, o `=` abreviado é um erro.
+    fn parse_assignment_or_cover(&mut self, allow_cover: bool) -> Result<Expression, ParseError> {
+        let outer = self.cover_initializer.take();
+        let result = self.nested(Self::parse_assignment_expression);
+        let pending = std::mem::replace(&mut self.cover_initializer, outer);
+        let expression = result?;
+        if let Some(span) = pending {
+            if !allow_cover || !matches!(expression, Expression::ObjectExpression { .. } | Expression::ArrayExpression { .. }) {
+                return Err(cover_initializer_error(span));
+            }
+            self.cover_initializer.get_or_insert(span);
+        }
+        Ok(expression)
+    }
+
+    fn parse_assignment_expression(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        if self.check(TokenKind::Yield) {
+            if !self.context.in_generator {
+                return Err(ParseError::new(
+                    ErrorCode::YieldOutsideGenerator,
+                    self.current_span(),
+                    "'yield' só pode ser usado dentro de um generator",
+                ));
+            }
+            self.advance();
+            return self.parse_yield_expression(start);
+        }
+        if let Some(arrow) = self.try_parse_arrow_function()? {
+            return Ok(arrow);
+        }
+
+        let left = self.parse_conditional()?;
+        if !self.peek_kind().is_some_and(|k| ASSIGNMENT_OPERATORS.contains(&k)) {
+            return Ok(left);
+        }
+
+        let operator = self.advance().value.clone();
+        // `[a, b] = ...` e `({ a } = ...)`: o literal já lido vira uma desestruturação
+        let left = if operator == "=" && is_unparenthesized_literal(&left, start) {
+            self.cover_initializer = None;
+            self.expression_to_pattern(left)?
+        } else {
+            self.expression_to_simple_target(left)?
+        };
+        // Atribuições associam à direita: `a = b = c` é `a = (b = c)`
+        let right = self.parse_assignment()?;
+        Ok(Expression::AssignmentExpression {
+            operator,
+            left: Box::new(left),
+            right: Box::new(right),
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_yield_expression(&mut self, start: Span) -> Result<Expression, ParseError> {
+        // `yield` sozinho termina numa quebra de linha ou onde não pode começar uma expressão
+        let has_argument = !self.has_newline_before()
+            && !matches!(
+                self.peek_kind(),
+                None | Some(
+                    TokenKind::RParen
+                        | TokenKind::RBracket
+                        | TokenKind::RBrace
+                        | TokenKind::Comma
+                        | TokenKind::Semicolon
+                        | TokenKind::Colon
+                )
+            );
+        let delegate = has_argument && self.match_token(TokenKind::Star);
+        let argument = if has_argument {
+            Some(Box::new(self.parse_assignment()?))
+        } else {
+            None
+        };
+        Ok(Expression::YieldExpression {
+            argument,
+            delegate,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Tenta ler uma arrow function (`x => ...`, `(a: T, b) => ...`, `async x => ...`).
+    /// Listas entre parênteses só são lidas como parâmetros se o `=>` vier depois do
+    /// `)` que as fecha; sem ele, voltamos ao início.
+    fn try_parse_arrow_function(&mut self) -> Result<Option<Expression>, ParseError> {
+        let in_consequent = std::mem::take(&mut self.in_conditional_consequent);
+        let checkpoint = self.checkpoint();
+        let start = self.start_span();
+        let is_async = self.check_contextual("async")
+            && self
+                .peek_next_kind()
+                .is_some_and(|k| k.can_be_identifier() || matches!(k, TokenKind::LParen | TokenKind::Lt))
+            && !self.has_newline_after();
+        if is_async {
+            self.advance();
+        }
+
+        let (type_parameters, params, return_type) = match self.peek_kind() {
+            Some(kind) if kind.can_be_identifier() && self.peek_next_kind() == Some(TokenKind::Arrow) => {
+                let id = self.parse_identifier()?;
+                let span = id.span;
+                let param = Param {
+                    decorators: Vec::new(),
+                    id: Pattern::Identifier(id),
+                    optional: false,
+                    type_annotation: None,
+                    parameter_property: None,
+                    span,
+                };
+                (None, vec![param], None)
+            }
+            // `<T>(x: T) => x`; sem o `=>`, pode ser uma conversão `<T>x`
+            Some(TokenKind::LParen | TokenKind::Lt) => {
+                let type_parameters = match self.parse_optional_type_parameters() {
+                    Ok(type_parameters) if self.check(TokenKind::LParen) && self.is_arrow_parameter_list() => {
+                        type_parameters
+                    }
+                    _ => {
+                        self.rollback(checkpoint);
+                        return Ok(None);
+                    }
+                };
+                let params = self.parse_params(false, false)?;
+                let return_type = self.parse_optional_return_type()?;
+                (type_parameters, params, return_type.map(Box::new))
+            }
+            _ => {
+                self.rollback(checkpoint);
+                return Ok(None);
+            }
+        };
+        if self.has_newline_before() {
+            return Err(ParseError::new(
+                ErrorCode::LineBreakNotAllowed,
+                self.current_span(),
+                "Não pode haver quebra de linha antes de '=>'",
+            ));
+        }
+        self.consume(TokenKind::Arrow)?;
+
+        let (body, expression) = if self.check(TokenKind::LBrace) {
+            (ArrowBody::BlockStatement(self.parse_function_body(is_async, false)?), false)
+        } else {
+            let outer = self.enter_function_context(is_async, false);
+            let body = self.parse_assignment();
+            self.context = outer;
+            (ArrowBody::Expression(Box::new(body?)), true)
+        };
+
+        // Entre `?` e `:`, `a ? (b) : c => d` é `a ? b : (c => d)`: o `(b): c => d`
+        // só fica como arrow se ainda vier o `:` da condicional
+        if in_consequent && return_type.is_some() && !self.check(TokenKind::Colon) {
+            self.rollback(checkpoint);
+            return Ok(None);
+        }
+
+        Ok(Some(Expression::ArrowFunctionExpression {
+            type_parameters,
+            params,
+            return_type,
+            body,
+            r#async: is_async,
+            expression,
+            span: self.finish_span(start),
+        }))
+    }
+
+    /// Se o `(` atual abre a lista de parâmetros de uma arrow function: depois do `)`
+    /// correspondente vem `=>` ou um tipo de retorno seguido de `=>`. Só o tipo de
+    /// retorno é lido de forma especulativa; o conteúdo dos parênteses é pulado.
+    fn is_arrow_parameter_list(&mut self) -> bool {
+        let mut depth = 0usize;
+        let mut index = self.current;
+        while let Some(token) = self.tokens.get(index) {
+            match token.kind {
+                TokenKind::LParen => depth += 1,
+                TokenKind::RParen => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+        match self.tokens.get(index + 1).map(|token| token.kind) {
+            Some(TokenKind::Arrow) => true,
+            Some(TokenKind::Colon) => {
+                let checkpoint = self.checkpoint();
+                self.current = index + 1;
+                let is_arrow = self.parse_optional_return_type().is_ok() && self.check(TokenKind::Arrow);
+                self.rollback(checkpoint);
+                is_arrow
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_conditional(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        let test = self.parse_binary(0)?;
+        if !self.match_token(TokenKind::Question) {
+            return Ok(test);
+        }
+
+        self.in_conditional_consequent = true;
+        let consequent = self.with_allow_in(true, |parser| parser.parse_assignment());
+        self.in_conditional_consequent = false;
+        let consequent = consequent?;
+        self.consume(TokenKind::Colon)?;
+        let alternate = self.parse_assignment()?;
+        Ok(Expression::ConditionalExpression {
+            test: Box::new(test),
+            consequent: Box::new(consequent),
+            alternate: Box::new(alternate),
+            span: self.finish_span(start),
+        })
     }
 
-    fn parse_logical_and(&mut self) -> Result<Expression, ParseError> {
-        let mut left = self.parse_equality()?;
+    /// Operadores binários por força de ligação (Pratt). `min_power` é a força mínima
+    /// que um operador precisa ter para ser absorvido por esta chamada.
+    fn parse_binary(&mut self, min_power: u8) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        let starts_with_unary = self.is_unary_operator();
+        let mut left = self.parse_unary()?;
+        let mut is_operand = true;
 
-        while self.match_operator("&&") {
-            let operator = self.previous().value.clone();
-            let right = self.parse_equality()?;
-            left = Expression::BinaryExpression {
-                operator,
-                left: Box::new(left),
-                right: Box::new(right),
+        while let Some(kind) = self.peek_kind() {
+            // `x as T` e `x satisfies T` ligam como os operadores relacionais, mas
+            // o lado direito é um tipo
+            if (kind == TokenKind::As || self.check_contextual("satisfies")) && !self.has_newline_before() {
+                if RELATIONAL_POWER < min_power {
+                    break;
+                }
+                left = self.parse_type_cast(start, left)?;
+                is_operand = false;
+                continue;
+            }
+            let Some((left_power, right_power)) = binary_binding_power(kind) else {
+                break;
             };
-        }
-
-        Ok(left)
-    }
+            // No cabeçalho de um `for`, o `in` separa a variável do objeto
+            if left_power < min_power || (kind == TokenKind::In && !self.allow_in) {
+                break;
+            }
+            if kind == TokenKind::StarStar && is_operand && starts_with_unary {
+                return Err(ParseError::new(
+                    ErrorCode::UnparenthesizedUnaryExponent,
+                    self.finish_span(start),
+                    "Uma expressão unária à esquerda de '**' precisa estar entre parênteses",
+                ));
+            }
 
-    fn parse_equality(&mut self) -> Result<Expression, ParseError> {
-        let mut left = self.parse_relational()?;
+            let is_logical = matches!(kind, TokenKind::PipePipe | TokenKind::AmpAmp | TokenKind::QuestionQuestion);
+            // O lado direito nunca contém um `||`/`&&`/`??` sem parênteses; o esquerdo
+            // só contém se foi montado neste laço
+            if is_logical && !is_operand {
+                if let Expression::LogicalExpression { operator, .. } = &left {
+                    if (operator == "??") != (kind == TokenKind::QuestionQuestion) {
+                        return Err(ParseError::new(
+                            ErrorCode::MixedNullishCoalescing,
+                            self.current_span(),
+                            "'??' não pode ser misturado com '||' ou '&&' sem parênteses",
+                        ));
+                    }
+                }
+            }
 
-        while self.match_operator("==") || self.match_operator("!=") {
-            let operator = self.previous().value.clone();
-            let right = self.parse_relational()?;
-            left = Expression::BinaryExpression {
-                operator,
-                left: Box::new(left),
-                right: Box::new(right),
+            let operator = self.advance().value.clone();
+            let right = self.parse_binary(right_power)?;
+            left = if is_logical {
+                Expression::LogicalExpression {
+                    operator,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    span: self.finish_span(start),
+                }
+            } else {
+                Expression::BinaryExpression {
+                    operator,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    span: self.finish_span(start),
+                }
             };
+            is_operand = false;
         }
 
         Ok(left)
     }
 
-    fn parse_relational(&mut self) -> Result<Expression, ParseError> {
-        let mut left = self.parse_additive()?;
+    /// Lado direito de `as` ou `satisfies`.
+    fn parse_type_cast(&mut self, start: Span, expression: Expression) -> Result<Expression, ParseError> {
+        if self.match_token(TokenKind::As) {
+            let type_annotation = self.parse_cast_type()?;
+            return Ok(Expression::TSAsExpression {
+                expression: Box::new(expression),
+                type_annotation: Box::new(type_annotation),
+                span: self.finish_span(start),
+            });
+        }
+        self.advance();
+        let type_annotation = self.parse_type()?;
+        Ok(Expression::TSSatisfiesExpression {
+            expression: Box::new(expression),
+            type_annotation: Box::new(type_annotation),
+            span: self.finish_span(start),
+        })
+    }
 
-        while self.match_operator("<") || self.match_operator(">") || self.match_operator("<=") || self.match_operator(">=") {
-            let operator = self.previous().value.clone();
-            let right = self.parse_additive()?;
-            left = Expression::BinaryExpression {
-                operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
+    /// Tipo de uma conversão `as T` ou `<T>x`, onde `const` também é aceito.
+    fn parse_cast_type(&mut self) -> Result<TypeNode, ParseError> {
+        if self.match_token(TokenKind::Const) {
+            return Ok(TypeNode::TSConstType { span: self.last_token });
         }
+        self.parse_type()
+    }
 
-        Ok(left)
+    fn is_unary_operator(&self) -> bool {
+        matches!(
+            self.peek_kind(),
+            Some(
+                TokenKind::Bang
+                    | TokenKind::Tilde
+                    | TokenKind::Plus
+                    | TokenKind::Minus
+                    | TokenKind::Typeof
+                    | TokenKind::Void
+                    | TokenKind::Delete
+            )
+        ) || (self.context.in_async && self.check_contextual("await"))
     }
 
-    fn parse_additive(&mut self) -> Result<Expression, ParseError> {
-        let mut left = self.parse_multiplicative()?;
+    fn parse_unary(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        // `<T>x`: como a tentativa de arrow genérica já falhou, o `<` só pode ser uma conversão
+        if self.match_token(TokenKind::Lt) {
+            let type_annotation = self.parse_cast_type()?;
+            if !self.match_closing_angle() {
+                return Err(self.expected_token(TokenKind::Gt));
+            }
+            let expression = self.nested(Self::parse_unary)?;
+            return Ok(Expression::TSTypeAssertion {
+                type_annotation: Box::new(type_annotation),
+                expression: Box::new(expression),
+                span: self.finish_span(start),
+            });
+        }
+        if !self.is_unary_operator() {
+            return self.parse_update();
+        }
 
-        while self.match_operator("+") || self.match_operator("-") {
-            let operator = self.previous().value.clone();
-            let right = self.parse_multiplicative()?;
-            left = Expression::BinaryExpression {
-                operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
+        if self.check_contextual("await") {
+            self.advance();
+            let argument = self.nested(Self::parse_unary)?;
+            return Ok(Expression::AwaitExpression {
+                argument: Box::new(argument),
+                span: self.finish_span(start),
+            });
         }
 
-        Ok(left)
+        let operator = self.advance().value.clone();
+        let argument = self.nested(Self::parse_unary)?;
+        if operator == "delete" && matches!(argument, Expression::Identifier(_)) {
+            return Err(ParseError::new(
+                ErrorCode::DeleteIdentifier,
+                self.finish_span(start),
+                "'delete' não pode ser aplicado a um identificador em modo estrito",
+            ));
+        }
+        Ok(Expression::UnaryExpression {
+            operator,
+            argument: Box::new(argument),
+            prefix: true,
+            span: self.finish_span(start),
+        })
     }
 
-    fn parse_multiplicative(&mut self) -> Result<Expression, ParseError> {
-        let mut left = self.parse_unary()?;
-
-        while self.match_operator("*") || self.match_operator("/") {
+    /// `++x`, `--x`, `x++` e `x--`
+    fn parse_update(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        if self.match_token(TokenKind::PlusPlus) || self.match_token(TokenKind::MinusMinus) {
             let operator = self.previous().value.clone();
-            let right = self.parse_unary()?;
-            left = Expression::BinaryExpression {
+            let argument = self.nested(Self::parse_unary)?;
+            self.check_update_target(&argument)?;
+            return Ok(Expression::UpdateExpression {
                 operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            };
+                argument: Box::new(argument),
+                prefix: true,
+                span: self.finish_span(start),
+            });
         }
 
-        Ok(left)
-    }
-
-    fn parse_unary(&mut self) -> Result<Expression, ParseError> {
-        if self.match_operator("!") || self.match_operator("-") || self.match_keyword("typeof") {
+        let argument = self.parse_member_call_expression()?;
+        // `a\n++b` são duas instruções: o `++` pós-fixo precisa estar na mesma linha
+        if !self.has_newline_before() && (self.match_token(TokenKind::PlusPlus) || self.match_token(TokenKind::MinusMinus)) {
             let operator = self.previous().value.clone();
-            let argument = self.parse_unary()?;
-            Ok(Expression::UnaryExpression {
+            self.check_update_target(&argument)?;
+            return Ok(Expression::UpdateExpression {
                 operator,
                 argument: Box::new(argument),
-                prefix: true,
-            })
+                prefix: false,
+                span: self.finish_span(start),
+            });
+        }
+        Ok(argument)
+    }
+
+    fn check_update_target(&self, argument: &Expression) -> Result<(), ParseError> {
+        if is_simple_target(argument) {
+            Ok(())
         } else {
-            self.parse_member_call_expression()
+            Err(ParseError::new(
+                ErrorCode::InvalidAssignmentTarget,
+                argument.span(),
+                "O operando de '++' ou '--' deve ser uma variável ou um acesso a propriedade",
+            ))
         }
     }
 
+    /// Alvos de `+=`, `-=`... (e de `=` fora de desestruturações): variável ou propriedade.
+    fn expression_to_simple_target(&self, expression: Expression) -> Result<Pattern, ParseError> {
+        match expression {
+            Expression::Identifier(identifier) => Ok(Pattern::Identifier(identifier)),
+            _ if is_simple_target(&expression) => Ok(Pattern::Expression(Box::new(expression))),
+            _ => Err(ParseError::new(
+                ErrorCode::InvalidAssignmentTarget,
+                expression.span(),
+                "Lado esquerdo de uma atribuição deve ser uma variável ou um acesso a propriedade",
+            )),
+        }
+    }
+
+    /// Dentro de delimitadores, `in` volta a ser operador mesmo no cabeçalho de um `for`.
+    fn with_allow_in<T>(
+        &mut self,
+        allow_in: bool,
+        parse: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let outer = std::mem::replace(&mut self.allow_in, allow_in);
+        let result = parse(self);
+        self.allow_in = outer;
+        result
+    }
+
     fn parse_member_call_expression(&mut self) -> Result<Expression, ParseError> {
-        let mut expr = self.parse_primary()?;
+        let start = self.start_span();
+        let expr = if self.check(TokenKind::New) {
+            self.parse_new_expression()?
+        } else {
+            self.parse_primary()?
+        };
+        self.parse_member_suffixes(start, expr, true)
+    }
+
+    /// Acessos, chamadas e templates marcados depois de `expr`. Sem `allow_call`,
+    /// para no primeiro `(`: o alvo de `new` é só uma expressão de membro, e os
+    /// argumentos que vêm depois dele pertencem ao `new`.
+    fn parse_member_suffixes(&mut self, start: Span, mut expr: Expression, allow_call: bool) -> Result<Expression, ParseError> {
+        let mut in_chain = false;
 
         loop {
-            if self.match_punctuation("(") {
-                expr = self.finish_call(expr)?;
-            } else if self.match_punctuation(".") {
-                let property = self.parse_identifier()?;
+            if !allow_call && matches!(self.peek_kind(), Some(TokenKind::LParen | TokenKind::Lt)) {
+                break;
+            }
+            let optional = self.match_token(TokenKind::QuestionDot);
+            if optional && !allow_call {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidOptionalChain,
+                    self.last_token,
+                    "O alvo de 'new' não pode ter encadeamento opcional",
+                ));
+            }
+            in_chain |= optional;
+            let type_arguments = self.try_parse_call_type_arguments();
+            if self.match_token(TokenKind::LParen) {
+                expr = self.finish_call(start, expr, type_arguments, optional)?;
+            } else if (optional && !self.check(TokenKind::LBracket)) || self.match_token(TokenKind::Dot) {
+                let property = if self.check(TokenKind::PrivateName) {
+                    let name = self.advance().value[1..].to_string();
+                    Expression::PrivateIdentifier {
+                        name,
+                        span: self.last_token,
+                    }
+                } else {
+                    Expression::Identifier(self.parse_property_identifier()?)
+                };
                 expr = Expression::MemberExpression {
                     object: Box::new(expr),
-                    property: Box::new(Expression::Identifier(property)),
+                    property: Box::new(property),
                     computed: false,
+                    optional,
+                    span: self.finish_span(start),
                 };
-            } else if self.match_punctuation("[") {
-                let property = self.parse_expression()?;
-                self.consume(TokenType::Punctuation, "]")?;
+            } else if self.match_token(TokenKind::LBracket) {
+                let property = self.with_allow_in(true, |parser| parser.parse_expression())?;
+                self.consume(TokenKind::RBracket)?;
                 expr = Expression::MemberExpression {
                     object: Box::new(expr),
                     property: Box::new(property),
                     computed: true,
+                    optional,
+                    span: self.finish_span(start),
+                };
+            } else if self.check(TokenKind::Bang) && !self.has_newline_before() {
+                self.advance();
+                expr = Expression::TSNonNullExpression {
+                    expression: Box::new(expr),
+                    span: self.finish_span(start),
+                };
+            } else if self.check(TokenKind::NoSubstitutionTemplate) || self.check(TokenKind::TemplateHead) {
+                if in_chain {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidOptionalChain,
+                        self.current_span(),
+                        "Um template marcado não pode fazer parte de um encadeamento opcional",
+                    ));
+                }
+                self.advance();
+                let quasi = self.parse_template_literal(true)?;
+                expr = Expression::TaggedTemplateExpression {
+                    tag: Box::new(expr),
+                    type_arguments,
+                    quasi,
+                    span: self.finish_span(start),
                 };
             } else {
                 break;
             }
         }
 
+        if in_chain {
+            expr = Expression::ChainExpression {
+                expression: Box::new(expr),
+                span: self.finish_span(start),
+            };
+        }
         Ok(expr)
     }
 
-    fn finish_call(&mut self, callee: Expression) -> Result<Expression, ParseError> {
-        let mut arguments = Vec::new();
-        if !self.check_value(")") {
-            loop {
-                arguments.push(self.parse_expression()?);
-                if !self.match_punctuation(",") {
-                    break;
-                }
+    /// `new Foo(...)`, `new Foo` ou `new.target`. O `new` mais interno fica com
+    /// os primeiros argumentos: `new new Foo()()`.
+    fn parse_new_expression(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::New)?;
+        let meta = Identifier {
+            name: "new".to_string(),
+            span: self.last_token,
+        };
+        if self.match_token(TokenKind::Dot) {
+            return self.parse_meta_property(start, meta, "target");
+        }
+
+        let callee_start = self.start_span();
+        let callee = if self.check(TokenKind::New) {
+            self.parse_new_expression()?
+        } else {
+            self.parse_primary()?
+        };
+        if matches!(callee, Expression::ImportExpression { .. }) {
+            return Err(ParseError::new(
+                ErrorCode::ExpressionExpected,
+                callee.span(),
+                "'import(...)' não pode ser usado com 'new'",
+            ));
+        }
+        let callee = self.parse_member_suffixes(callee_start, callee, false)?;
+        let type_arguments = self.try_parse_new_type_arguments();
+        let arguments = if self.match_token(TokenKind::LParen) {
+            self.parse_arguments()?
+        } else {
+            Vec::new()
+        };
+        Ok(Expression::NewExpression {
+            callee: Box::new(callee),
+            type_arguments,
+            arguments,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Como `try_parse_call_type_arguments`, mas os parênteses são opcionais
+    /// (`new Foo<T>;`): a lista também vale onde a expressão pode terminar.
+    fn try_parse_new_type_arguments(&mut self) -> Option<Vec<TypeNode>> {
+        if !self.check(TokenKind::Lt) {
+            return None;
+        }
+        let checkpoint = self.checkpoint();
+        match self.parse_type_arguments() {
+            Ok(arguments)
+                if self.can_insert_semicolon()
+                    || matches!(
+                        self.peek_kind(),
+                        Some(
+                            TokenKind::LParen
+                                | TokenKind::Semicolon
+                                | TokenKind::RParen
+                                | TokenKind::RBracket
+                                | TokenKind::Comma
+                                | TokenKind::Colon
+                        )
+                    ) =>
+            {
+                Some(arguments)
+            }
+            _ => {
+                self.rollback(checkpoint);
+                None
+            }
+        }
+    }
+
+    /// Resto de `new.target` e `import.meta`, depois do `.`.
+    fn parse_meta_property(&mut self, start: Span, meta: Identifier, property: &str) -> Result<Expression, ParseError> {
+        let identifier = self.parse_property_identifier()?;
+        if identifier.name != property {
+            return Err(ParseError::new(
+                ErrorCode::InvalidMetaProperty,
+                identifier.span,
+                format!("'{}.{}' não existe; o esperado era '{}.{}'", meta.name, identifier.name, meta.name, property),
+            ));
+        }
+        Ok(Expression::MetaProperty {
+            meta,
+            property: identifier,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// `import(fonte)` e `import(fonte, { with: ... })`, com o `(` atual.
+    fn parse_import_expression(&mut self, start: Span) -> Result<Expression, ParseError> {
+        self.consume(TokenKind::LParen)?;
+        let (source, options) = self.with_allow_in(true, |parser| {
+            let source = parser.parse_assignment()?;
+            let mut options = None;
+            if parser.match_token(TokenKind::Comma) && !parser.check(TokenKind::RParen) {
+                options = Some(Box::new(parser.parse_assignment()?));
+                parser.match_token(TokenKind::Comma);
+            }
+            Ok((source, options))
+        })?;
+        self.consume(TokenKind::RParen)?;
+        Ok(Expression::ImportExpression {
+            source: Box::new(source),
+            options,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Argumentos de tipo de uma chamada (`f<T>(x)`) ou de um template marcado. Como
+    /// `<` também é o operador menor que, a lista é lida de forma especulativa e só
+    /// vale se vier seguida de `(` ou de um template; senão, o parser volta ao `<`.
+    fn try_parse_call_type_arguments(&mut self) -> Option<Vec<TypeNode>> {
+        if !self.check(TokenKind::Lt) {
+            return None;
+        }
+        let checkpoint = self.checkpoint();
+        match self.parse_type_arguments() {
+            Ok(arguments)
+                if matches!(
+                    self.peek_kind(),
+                    Some(TokenKind::LParen | TokenKind::NoSubstitutionTemplate | TokenKind::TemplateHead)
+                ) =>
+            {
+                Some(arguments)
+            }
+            _ => {
+                self.rollback(checkpoint);
+                None
             }
         }
-        self.consume(TokenType::Punctuation, ")")?;
+    }
 
+    fn finish_call(
+        &mut self,
+        start: Span,
+        callee: Expression,
+        type_arguments: Option<Vec<TypeNode>>,
+        optional: bool,
+    ) -> Result<Expression, ParseError> {
+        let arguments = self.parse_arguments()?;
         Ok(Expression::CallExpression {
             callee: Box::new(callee),
+            type_arguments,
             arguments,
+            optional,
+            span: self.finish_span(start),
         })
     }
 
+    /// Argumentos de uma chamada ou de um `new`, depois do `(`, até o `)`.
+    fn parse_arguments(&mut self) -> Result<Vec<Expression>, ParseError> {
+        let arguments = self.with_allow_in(true, |parser| {
+            let mut arguments = Vec::new();
+            while !parser.check(TokenKind::RParen) && !parser.is_at_end() {
+                arguments.push(parser.parse_spread_or_expression()?);
+                if !parser.match_token(TokenKind::Comma) {
+                    break;
+                }
+            }
+            Ok(arguments)
+        })?;
+        self.consume(TokenKind::RParen)?;
+        Ok(arguments)
+    }
+
     fn parse_primary(&mut self) -> Result<Expression, ParseError> {
-        if self.match_type(TokenType::Literal) {
-            return Ok(Expression::Literal {
-                value: self.previous().value.clone(),
-                raw: self.previous().value.clone(),
-            });
+        let start = self.start_span();
+        if matches!(
+            self.peek_kind(),
+            Some(
+                TokenKind::NumericLiteral
+                    | TokenKind::BigIntLiteral
+                    | TokenKind::StringLiteral
+                    | TokenKind::True
+                    | TokenKind::False
+                    | TokenKind::Null
+            )
+        ) {
+            return Ok(self.parse_literal());
         }
-        if self.match_keyword("true") {
-            return Ok(Expression::Literal {
-                value: "true".to_string(),
-                raw: "true".to_string(),
+        if self.match_token(TokenKind::RegExpLiteral) {
+            let raw = self.previous().value.clone();
+            // O lexer garante que o padrão começa com `/`; as flags vêm depois da última barra
+            let end = raw.rfind('/').filter(|&i| i > 0).unwrap_or(raw.len());
+            return Ok(Expression::RegExpLiteral {
+                pattern: raw[1..end].to_string(),
+                flags: raw.get(end + 1..).unwrap_or("").to_string(),
+                raw,
+                span: self.last_token,
             });
         }
-        if self.match_keyword("false") {
-            return Ok(Expression::Literal {
-                value: "false".to_string(),
-                raw: "false".to_string(),
-            });
+        if self.check(TokenKind::NoSubstitutionTemplate) || self.check(TokenKind::TemplateHead) {
+            self.advance();
+            return Ok(Expression::TemplateLiteral(self.parse_template_literal(false)?));
+        }
+        if self.check(TokenKind::LBrace) {
+            return self.with_allow_in(true, |parser| parser.parse_object_expression());
+        }
+        if self.check(TokenKind::LBracket) {
+            return self.with_allow_in(true, |parser| parser.parse_array_expression());
+        }
+        if self.check(TokenKind::Class) || self.check(TokenKind::At) {
+            let decorators = self.parse_decorators()?;
+            if !self.match_token(TokenKind::Class) {
+                // Também chega aqui uma arrow com parâmetros decorados, `(@d x) => x`
+                return Err(self.unexpected(
+                    ErrorCode::InvalidDecorator,
+                    "Decoradores só podem ser usados em classes, em seus membros e em parâmetros: 'class' esperado",
+                ));
+            }
+            return Ok(Expression::ClassExpression(self.parse_class(start, false, false, decorators)?));
+        }
+        if self.check(TokenKind::Function)
+            || (self.check_contextual("async")
+                && self.peek_next_kind() == Some(TokenKind::Function)
+                && !self.has_newline_after())
+        {
+            let is_async = !self.check(TokenKind::Function);
+            if is_async {
+                self.advance();
+            }
+            self.advance();
+            return self.parse_function_expression(start, is_async);
+        }
+        if self.match_token(TokenKind::This) {
+            return Ok(Expression::ThisExpression { span: self.last_token });
+        }
+        if self.match_token(TokenKind::Super) {
+            // `super` sozinho não é uma expressão
+            if !matches!(self.peek_kind(), Some(TokenKind::LParen | TokenKind::Dot | TokenKind::LBracket)) {
+                return Err(self.unexpected(ErrorCode::ExpectedToken, "'(', '.' ou '[' esperado depois de 'super'"));
+            }
+            return Ok(Expression::Super { span: self.last_token });
+        }
+        if self.match_token(TokenKind::Import) {
+            let meta = Identifier {
+                name: "import".to_string(),
+                span: self.last_token,
+            };
+            if self.match_token(TokenKind::Dot) {
+                return self.parse_meta_property(start, meta, "meta");
+            }
+            return self.parse_import_expression(start);
         }
-        if self.match_type(TokenType::Identifier) {
+        if self.check_identifier() {
             return Ok(Expression::Identifier(Identifier {
-                name: self.previous().value.clone(),
+                name: self.advance().value.clone(),
+                span: self.last_token,
             }));
         }
-        if self.match_punctuation("(") {
-            let expr = self.parse_expression()?;
-            self.consume(TokenType::Punctuation, ")")?;
+        if self.match_token(TokenKind::LParen) {
+            let expr = self.with_allow_in(true, |parser| parser.parse_expression())?;
+            self.consume(TokenKind::RParen)?;
             return Ok(expr);
         }
         
-        Err(ParseError::UnexpectedToken {
-            expected: "Expressão".to_string(),
-            found: self.peek().map(|t| t.value.clone()).unwrap_or_else(|| "Fim de Arquivo".to_string()),
-        })
+        Err(self.unexpected(ErrorCode::ExpressionExpected, "Expressão esperada"))
     }
 
-    fn parse_identifier(&mut self) -> Result<Identifier, ParseError> {
-        if self.match_type(TokenType::Identifier) {
-            Ok(Identifier {
-                name: self.previous().value.clone(),
-            })
-        } else {
-            Err(ParseError::UnexpectedToken {
-                expected: "Identificador".to_string(),
-                found: self.peek().map(|t| t.value.clone()).unwrap_or_else(|| "Fim de Arquivo".to_string()),
-            })
+    /// Argumento de uma chamada ou de um `new`: uma expressão ou `...x`.
+    fn parse_spread_or_expression(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        if self.match_token(TokenKind::Ellipsis) {
+            let argument = self.parse_assignment()?;
+            return Ok(Expression::SpreadElement {
+                argument: Box::new(argument),
+                span: self.finish_span(start),
+            });
         }
+        self.parse_assignment()
     }
 
-    // Helpers
-    fn consume_type_annotation(&mut self) -> String {
-    // Função auxiliar para pular a anotação de tipo (ex: : string)
-    // Necessário porque nossa AST ainda não guarda informações de tipo complexas
-        if self.match_type(TokenType::Identifier) || self.match_type(TokenType::Keyword) {
-            self.previous().value.clone()
-        } else {
-            String::new()
+    fn parse_array_expression(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::LBracket)?;
+        let mut elements = Vec::new();
+
+        while !self.check(TokenKind::RBracket) && !self.is_at_end() {
+            if self.match_token(TokenKind::Comma) {
+                elements.push(None);
+                continue;
+            }
+            let element = if self.check(TokenKind::Ellipsis) {
+                let element_start = self.start_span();
+                self.advance();
+                let argument = self.parse_assignment_or_cover(true)?;
+                if self.check(TokenKind::Comma) {
+                    self.spreads_before_comma.insert(element_start.start);
+                }
+                Expression::SpreadElement {
+                    argument: Box::new(argument),
+                    span: self.finish_span(element_start),
+                }
+            } else {
+                self.parse_assignment_or_cover(true)?
+            };
+            elements.push(Some(element));
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
         }
+
+        self.consume(TokenKind::RBracket)?;
+        Ok(Expression::ArrayExpression {
+            elements,
+            span: self.finish_span(start),
+        })
     }
 
-    fn match_keyword(&mut self, keyword: &str) -> bool {
-        if self.check(TokenType::Keyword) && self.peek().unwrap().value == keyword {
-            self.advance();
-            true
-        } else {
-            false
+    fn parse_object_expression(&mut self) -> Result<Expression, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::LBrace)?;
+        let mut properties = Vec::new();
+
+        while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+            properties.push(self.parse_object_property()?);
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
         }
+
+        self.consume(TokenKind::RBrace)?;
+        Ok(Expression::ObjectExpression {
+            properties,
+            span: self.finish_span(start),
+        })
     }
 
-    fn match_operator(&mut self, op: &str) -> bool {
-        if self.check(TokenType::Operator) && self.peek().unwrap().value == op {
+    fn parse_object_property(&mut self) -> Result<ObjectProperty, ParseError> {
+        let start = self.start_span();
+        if self.match_token(TokenKind::Ellipsis) {
+            let argument = self.parse_assignment_or_cover(true)?;
+            return Ok(ObjectProperty::SpreadElement {
+                argument,
+                span: self.finish_span(start),
+            });
+        }
+
+        // Em `{ get: 1 }` ou `{ async }`, a palavra é o próprio nome
+        let mut kind = "init";
+        let mut is_async = false;
+        if matches!(self.peek_kind(), Some(TokenKind::Get | TokenKind::Set)) && self.is_modifier_position() {
+            kind = if self.advance().kind == TokenKind::Get { "get" } else { "set" };
+        } else if self.check_contextual("async") && self.is_modifier_position() && !self.has_newline_after() {
             self.advance();
-            true
-        } else {
-            false
+            is_async = true;
+        }
+        let generator = kind == "init" && self.match_token(TokenKind::Star);
+
+        let is_identifier = self.check_identifier();
+        let (key, computed) = self.parse_property_name()?;
+        if matches!(key, Expression::PrivateIdentifier { .. }) {
+            return Err(ParseError::new(
+                ErrorCode::PrivateNameOutsideClass,
+                key.span(),
+                "Nomes privados só podem ser usados dentro de classes",
+            ));
+        }
+
+        if self.check(TokenKind::LParen) || self.check(TokenKind::Lt) {
+            let function_start = self.start_span();
+            let type_parameters = self.parse_optional_type_parameters()?;
+            if type_parameters.is_some() && kind != "init" {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidAccessor,
+                    self.finish_span(function_start),
+                    "Um acessor não pode ter parâmetros de tipo",
+                ));
+            }
+            let params = self.parse_params(false, false)?;
+            if kind == "get" && !params.is_empty() {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidAccessor,
+                    key.span(),
+                    "Um acessor 'get' não pode ter parâmetros",
+                ));
+            }
+            if kind == "set" && params.len() != 1 {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidAccessor,
+                    key.span(),
+                    "Um acessor 'set' deve ter exatamente um parâmetro",
+                ));
+            }
+            let return_type = self.parse_optional_return_type()?.map(Box::new);
+            let body = self.parse_function_body(is_async, generator)?;
+            return Ok(ObjectProperty::Property {
+                key: Box::new(key),
+                value: Expression::FunctionExpression {
+                    id: None,
+                    type_parameters,
+                    params,
+                    return_type,
+                    body,
+                    r#async: is_async,
+                    generator,
+                    span: self.finish_span(function_start),
+                },
+                computed,
+                shorthand: false,
+                method: kind == "init",
+                kind: kind.to_string(),
+                span: self.finish_span(start),
+            });
+        }
+
+        if kind != "init" || is_async || generator {
+            return Err(self.expected_token(TokenKind::LParen));
+        }
+
+        if self.match_token(TokenKind::Colon) {
+            let value = self.parse_assignment_or_cover(true)?;
+            return Ok(ObjectProperty::Property {
+                key: Box::new(key),
+                value,
+                computed,
+                shorthand: false,
+                method: false,
+                kind: kind.to_string(),
+                span: self.finish_span(start),
+            });
+        }
+
+        // `{ a }` abrevia `{ a: a }`; `{ a = 1 }` só vale em uma desestruturação
+        match &key {
+            Expression::Identifier(Identifier { name, span }) if is_identifier && !computed => {
+                let identifier = Identifier {
+                    name: name.clone(),
+                    span: *span,
+                };
+                let value = if self.match_token(TokenKind::Eq) {
+                    self.cover_initializer.get_or_insert(self.last_token);
+                    let right = self.parse_assignment()?;
+                    Expression::AssignmentExpression {
+                        operator: "=".to_string(),
+                        left: Box::new(Pattern::Identifier(identifier)),
+                        right: Box::new(right),
+                        span: self.finish_span(start),
+                    }
+                } else {
+                    Expression::Identifier(identifier)
+                };
+                Ok(ObjectProperty::Property {
+                    key: Box::new(key),
+                    value,
+                    computed,
+                    shorthand: true,
+                    method: false,
+                    kind: kind.to_string(),
+                    span: self.finish_span(start),
+                })
+            }
+            _ => Err(self.expected_token(TokenKind::Colon)),
         }
     }
 
-    fn match_punctuation(&mut self, p: &str) -> bool {
-        if self.check(TokenType::Punctuation) && self.peek().unwrap().value == p {
-            self.advance();
-            true
+    fn parse_function_expression(&mut self, start: Span, is_async: bool) -> Result<Expression, ParseError> {
+        let generator = self.match_token(TokenKind::Star);
+        let id = if self.check_identifier() {
+            Some(self.parse_identifier()?)
         } else {
-            false
+            None
+        };
+        let type_parameters = self.parse_optional_type_parameters()?;
+        let params = self.parse_params(false, false)?;
+        let return_type = self.parse_optional_return_type()?.map(Box::new);
+        let body = self.parse_function_body(is_async, generator)?;
+
+        Ok(Expression::FunctionExpression {
+            id,
+            type_parameters,
+            params,
+            return_type,
+            body,
+            r#async: is_async,
+            generator,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Lê um template cujo token de abertura acabou de ser consumido.
+    /// Em um template marcado (`tagged`), escapes inválidos são permitidos e só
+    /// deixam o `cooked` vazio.
+    fn parse_template_literal(&mut self, tagged: bool) -> Result<TemplateLiteral, ParseError> {
+        let start = self.last_token;
+        let (quasis, expressions) = self.parse_template_parts(tagged, |parser| {
+            parser.with_allow_in(true, |parser| parser.parse_expression())
+        })?;
+        Ok(TemplateLiteral {
+            quasis,
+            expressions,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Percorre os pedaços de um template (expressão ou tipo), chamando
+    /// `parse_part` para cada substituição `${ ... }`.
+    fn parse_template_parts<T>(
+        &mut self,
+        tagged: bool,
+        mut parse_part: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<(Vec<TemplateElement>, Vec<T>), ParseError> {
+        let mut quasis = Vec::new();
+        let mut parts = Vec::new();
+
+        loop {
+            let token = self.previous();
+            let value = &token.value;
+            // Pedaços abrem com `` ` `` ou `}` e fecham com `` ` `` ou `${`
+            let tail = matches!(token.kind, TokenKind::NoSubstitutionTemplate | TokenKind::TemplateTail);
+            let end = if tail {
+                value.len() - value.ends_with('`') as usize
+            } else {
+                value.len() - 2
+            };
+            let raw = value[1..end.max(1)].to_string();
+            // Quebras de linha `\r\n` e `\r` valem como `\n` no texto interpretado
+            let (cooked, invalid_escape) = unescape(&raw.replace("\r\n", "\n").replace('\r', "\n"));
+            if let (Some(message), false) = (&invalid_escape, tagged) {
+                self.errors
+                    .push(ParseError::new(ErrorCode::InvalidEscapeSequence, self.last_token, message.clone()));
+            }
+            quasis.push(TemplateElement {
+                raw,
+                cooked: invalid_escape.is_none().then_some(cooked),
+                tail,
+                span: self.last_token,
+            });
+            if tail {
+                break;
+            }
+
+            parts.push(parse_part(self)?);
+            if self.check(TokenKind::TemplateMiddle) || self.check(TokenKind::TemplateTail) {
+                self.advance();
+            } else {
+                return Err(self.expected_token(TokenKind::RBrace));
+            }
+        }
+
+        Ok((quasis, parts))
+    }
+
+    /// Nome de variável, função, classe ou rótulo. Palavras-chave contextuais
+    /// (`type`, `from`, `get`...) valem; palavras reservadas não.
+    fn parse_identifier(&mut self) -> Result<Identifier, ParseError> {
+        match self.peek() {
+            // Em módulos, `await` é reservada mesmo fora de funções `async`
+            Some(token) if token.kind == TokenKind::Identifier && token.value == "await" => Err(self.reserved_word()),
+            Some(token) if token.kind.can_be_identifier() => Ok(Identifier {
+                name: self.advance().value.clone(),
+                span: self.last_token,
+            }),
+            Some(token) if token.kind.is_keyword() => Err(self.reserved_word()),
+            _ => Err(self.expected_token(TokenKind::Identifier)),
         }
     }
 
-    fn match_type(&mut self, token_type: TokenType) -> bool {
-        if self.check(token_type) {
+    /// Erro para uma palavra reservada (`class`, `static`...) usada como identificador.
+    fn reserved_word(&self) -> ParseError {
+        let Some(token) = self.peek() else {
+            return self.expected_token(TokenKind::Identifier);
+        };
+        let strict = if token.kind.is_strict_mode_reserved() || token.kind == TokenKind::Identifier {
+            " no modo estrito"
+        } else {
+            ""
+        };
+        ParseError::new(
+            ErrorCode::ReservedWord,
+            self.current_span(),
+            format!(
+                "'{}' é uma palavra reservada{} e não pode ser usada como identificador",
+                token.value, strict
+            ),
+        )
+    }
+
+    // Helpers
+    fn match_token(&mut self, kind: TokenKind) -> bool {
+        if self.check(kind) {
             self.advance();
             true
         } else {
@@ -469,36 +3344,176 @@ impl Parser {
         }
     }
 
-    fn check(&self, token_type: TokenType) -> bool {
-        if self.is_at_end() {
-            false
-        } else {
-            std::mem::discriminant(&self.peek().unwrap().token_type) == std::mem::discriminant(&token_type)
+    /// Fecha uma lista de argumentos de tipo (`Array<Array<T>>`). O lexer faz maximal
+    /// munch e entrega `>>`, `>>>`, `>=`, `>>=` ou `>>>=`; nesse caso consumimos só o
+    /// primeiro `>` e deixamos o restante como token atual.
+    fn match_closing_angle(&mut self) -> bool {
+        match self.peek_kind() {
+            Some(TokenKind::Gt) => {
+                self.advance();
+                true
+            }
+            Some(
+                TokenKind::GtEq
+                | TokenKind::ShiftRight
+                | TokenKind::ShiftRightEq
+                | TokenKind::UnsignedShiftRight
+                | TokenKind::UnsignedShiftRightEq,
+            ) => {
+                self.split_tokens.push((self.current, self.tokens[self.current].clone()));
+                let token = &mut self.tokens[self.current];
+                self.last_token = Span {
+                    end: token.position.start as u32 + 1,
+                    end_line: token.position.line as u32,
+                    end_column: token.position.column as u32 + 1,
+                    ..Span::of_token(token)
+                };
+                token.value.remove(0);
+                token.kind = TokenKind::from_punctuator(&token.value).unwrap();
+                token.position.start += 1;
+                token.position.column += 1;
+                true
+            }
+            _ => false,
         }
     }
 
-    fn check_value(&self, value: &str) -> bool {
-        if self.is_at_end() {
-            false
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            current: self.current,
+            splits: self.split_tokens.len(),
+            last_token: self.last_token,
+            errors: self.errors.len(),
+        }
+    }
+
+    /// Volta para um `checkpoint`, desfazendo também os tokens `>` quebrados e os
+    /// erros recuperados depois dele.
+    fn rollback(&mut self, checkpoint: Checkpoint) {
+        while self.split_tokens.len() > checkpoint.splits {
+            let (index, token) = self.split_tokens.pop().unwrap();
+            self.tokens[index] = token;
+        }
+        self.current = checkpoint.current;
+        self.last_token = checkpoint.last_token;
+        self.errors.truncate(checkpoint.errors);
+    }
+
+    /// Chama `parse` um nível mais fundo, ou devolve um erro se o limite de
+    /// aninhamento já foi atingido.
+    fn nested<T>(&mut self, parse: fn(&mut Self) -> Result<T, ParseError>) -> Result<T, ParseError> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(ParseError::new(
+                ErrorCode::NestingTooDeep,
+                self.current_span(),
+                format!("Expressão aninhada em mais de {} níveis", MAX_NESTING_DEPTH),
+            ));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Começo de um nó: a posição do próximo token (ou o fim do arquivo).
+    fn start_span(&self) -> Span {
+        match self.peek() {
+            Some(token) => {
+                let span = Span::of_token(token);
+                Span {
+                    end: span.start,
+                    end_line: span.start_line,
+                    end_column: span.start_column,
+                    ..span
+                }
+            }
+            None => Span {
+                start: self.last_token.end,
+                start_line: self.last_token.end_line,
+                start_column: self.last_token.end_column,
+                ..self.last_token
+            },
+        }
+    }
+
+    /// Fecha um nó aberto em `start` no fim do último token consumido.
+    fn finish_span(&self, start: Span) -> Span {
+        Span {
+            end: self.last_token.end,
+            end_line: self.last_token.end_line,
+            end_column: self.last_token.end_column,
+            ..start
+        }
+    }
+
+    fn check(&self, kind: TokenKind) -> bool {
+        let found = self.peek_kind() == Some(kind);
+        if !found {
+            self.expected_tokens.record(self.current, kind);
+        }
+        found
+    }
+
+    /// Identificador ou palavra-chave contextual, que pode ser usada como nome.
+    fn check_identifier(&self) -> bool {
+        self.check(TokenKind::Identifier) || self.peek_kind().is_some_and(TokenKind::is_contextual_keyword)
+    }
+
+    /// Palavras como `async`, `await` e `override` chegam do lexer como identificadores.
+    fn check_contextual(&self, word: &str) -> bool {
+        self.peek().is_some_and(|t| t.kind == TokenKind::Identifier && t.value == word)
+    }
+
+    fn peek_kind(&self) -> Option<TokenKind> {
+        self.peek().map(|t| t.kind)
+    }
+
+    fn peek_next_kind(&self) -> Option<TokenKind> {
+        self.peek_kind_at(1)
+    }
+
+    /// Tipo do token `offset` posições à frente do atual.
+    fn peek_kind_at(&self, offset: usize) -> Option<TokenKind> {
+        self.tokens.get(self.current + offset).map(|t| t.kind)
+    }
+
+    /// Se há uma quebra de linha entre o último token consumido e o atual.
+    fn has_newline_before(&self) -> bool {
+        self.newline_before.get(self.current).copied().unwrap_or(false)
+    }
+
+    /// Se há uma quebra de linha entre o token atual e o seguinte (`async\nfunction`).
+    fn has_newline_after(&self) -> bool {
+        self.newline_before.get(self.current + 1).copied().unwrap_or(false)
+    }
+
+    /// Inserção automática de `;`: ela acontece antes de `}`, no fim do arquivo
+    /// ou quando o próximo token está em outra linha.
+    fn can_insert_semicolon(&self) -> bool {
+        self.is_at_end() || self.check(TokenKind::RBrace) || self.has_newline_before()
+    }
+
+    /// `;` que termina uma instrução, que pode ser omitido onde a inserção automática vale.
+    fn consume_semicolon(&mut self) -> Result<(), ParseError> {
+        if self.match_token(TokenKind::Semicolon) || self.can_insert_semicolon() {
+            Ok(())
         } else {
-            self.peek().unwrap().value == value
+            Err(self.expected_token(TokenKind::Semicolon))
         }
     }
 
-    fn consume(&mut self, token_type: TokenType, value: &str) -> Result<&Token, ParseError> {
-        if self.check(token_type.clone()) && self.peek().unwrap().value == value {
+    fn consume(&mut self, kind: TokenKind) -> Result<&Token, ParseError> {
+        if self.check(kind) {
             Ok(self.advance())
         } else {
-            Err(ParseError::UnexpectedToken {
-                expected: value.to_string(),
-                found: self.peek().map(|t| t.value.clone()).unwrap_or_else(|| "Fim de Arquivo".to_string()),
-            })
+            Err(self.expected_token(kind))
         }
     }
 
     fn advance(&mut self) -> &Token {
         if !self.is_at_end() {
             self.current += 1;
+            self.last_token = Span::of_token(&self.tokens[self.current - 1]);
         }
         self.previous()
     }
@@ -0,0 +1,319 @@
+use super::{ClassMember, Parser, Span, Statement};
+use crate::lexer::TokenKind;
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::fmt;
+
+/// Define `ErrorCode` a partir da tabela de códigos, gerando junto a conversão para texto.
+macro_rules! error_codes {
+    ($($(#[doc = $doc:literal])* $name:ident => $code:literal,)*) => {
+        /// Código estável de um erro de análise, no estilo dos códigos do TypeScript
+        /// (`TS1005`), com numeração própria. Um código nunca muda de significado.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+        pub enum ErrorCode {
+            $($(#[doc = $doc])* #[serde(rename = $code)] $name,)*
+        }
+
+        impl ErrorCode {
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(ErrorCode::$name => $code,)*
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// Um token específico era esperado
+    ExpectedToken => "RTS1001",
+    UnexpectedEndOfFile => "RTS1002",
+    IdentifierExpected => "RTS1003",
+    ExpressionExpected => "RTS1004",
+    DeclarationExpected => "RTS1005",
+    /// Elemento ou parâmetro rest fora do lugar, com valor padrão ou opcional
+    InvalidRestElement => "RTS1101",
+    InvalidAssignmentTarget => "RTS1102",
+    InvalidBindingName => "RTS1103",
+    EmptyTypeParameterList => "RTS1104",
+    /// Forma inválida de `import`/`export`
+    InvalidModuleDeclaration => "RTS1105",
+    /// `import`/`export` fora do nível superior
+    ModuleDeclarationNotTopLevel => "RTS1106",
+    InvalidParameterProperty => "RTS1107",
+    /// Cabeçalho inválido de `for-in`, `for-of` ou `for await`
+    InvalidForHead => "RTS1108",
+    DuplicateDefaultClause => "RTS1109",
+    UndefinedLabel => "RTS1110",
+    DuplicateLabel => "RTS1111",
+    /// `break`/`continue` sem um laço, `switch` ou rótulo válido
+    InvalidJump => "RTS1112",
+    InvalidEnumMember => "RTS1113",
+    /// Modificador repetido, fora do lugar ou incompatível com o membro
+    InvalidModifier => "RTS1114",
+    /// Acessor `get`/`set` com o número errado de parâmetros
+    InvalidAccessor => "RTS1115",
+    InvalidConstructor => "RTS1116",
+    YieldOutsideGenerator => "RTS1117",
+    /// `-a ** b` sem parênteses
+    UnparenthesizedUnaryExponent => "RTS1118",
+    /// `delete x` em modo estrito
+    DeleteIdentifier => "RTS1119",
+    PrivateNameOutsideClass => "RTS1120",
+    /// Quebra de linha onde a gramática não permite (`throw`, antes de `=>`)
+    LineBreakNotAllowed => "RTS1121",
+    /// Palavra reservada usada como identificador (`let class`, `let static`)
+    ReservedWord => "RTS1122",
+    /// `??` misturado com `||` ou `&&` sem parênteses
+    MixedNullishCoalescing => "RTS1123",
+    /// Encadeamento opcional em um template marcado ou no alvo de `new`
+    InvalidOptionalChain => "RTS1124",
+    /// Propriedade desconhecida em `new.` ou `import.`
+    InvalidMetaProperty => "RTS1125",
+    /// Decorador fora de uma classe, de um membro ou de um parâmetro
+    InvalidDecorator => "RTS1126",
+    /// Declaração `let`, `const`, de função, classe ou tipo como corpo de um `if`, laço ou rótulo
+    DeclarationNotAllowed => "RTS1127",
+    /// Escape inválido em um template sem marca (`\u{zz}`, `\01`)
+    InvalidEscapeSequence => "RTS1128",
+    /// `const` ou desestruturação sem `=` fora do cabeçalho de um for-in/for-of
+    MissingInitializer => "RTS1129",
+    /// Expressões aninhadas além do limite do analisador
+    NestingTooDeep => "RTS1130",
+}
+
+/// Erro de análise: código, mensagem legível, trecho do código e os tokens que
+/// seriam aceitos no ponto do erro.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParseError {
+    pub code: ErrorCode,
+    pub message: String,
+    pub span: Span,
+    /// Vazio quando o erro não é de token inesperado
+    pub expected: Vec<String>,
+}
+
+impl ParseError {
+    pub fn new(code: ErrorCode, span: Span, message: impl Into<String>) -> Self {
+        ParseError {
+            code,
+            message: message.into(),
+            span,
+            expected: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{} {}: {}",
+            self.span.start_line,
+            self.span.start_column,
+            self.code.as_str(),
+            self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Cabem até 256 tipos de token no conjunto de esperados
+const _: () = assert!(TokenKind::ALL.len() <= 256);
+
+/// Tipos de token testados sem sucesso em uma posição. Se a análise falhar ali,
+/// eles formam a lista do que seria aceito.
+#[derive(Default)]
+pub(super) struct ExpectedTokens {
+    position: Cell<usize>,
+    kinds: Cell<[u64; 4]>,
+}
+
+impl ExpectedTokens {
+    pub(super) fn record(&self, position: usize, kind: TokenKind) {
+        let mut kinds = if self.position.get() == position {
+            self.kinds.get()
+        } else {
+            self.position.set(position);
+            [0; 4]
+        };
+        let index = kind as usize;
+        kinds[index / 64] |= 1 << (index % 64);
+        self.kinds.set(kinds);
+    }
+
+    fn at(&self, position: usize) -> impl Iterator<Item = TokenKind> {
+        let kinds = if self.position.get() == position {
+            self.kinds.get()
+        } else {
+            [0; 4]
+        };
+        TokenKind::ALL
+            .iter()
+            .copied()
+            .filter(move |&kind| kinds[kind as usize / 64] & (1 << (kind as usize % 64)) != 0)
+    }
+}
+
+/// `';'` para pontuadores e palavras-chave; a descrição (`Identificador`) para os demais.
+fn describe(kind: TokenKind) -> String {
+    let text = kind.as_str();
+    if kind.is_keyword() || TokenKind::from_punctuator(text) == Some(kind) {
+        format!("'{}'", text)
+    } else {
+        let mut chars = text.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl Parser {
+    /// Trecho do token atual (ou o fim do arquivo), onde os erros são apontados.
+    pub(super) fn current_span(&self) -> Span {
+        self.peek().map(Span::of_token).unwrap_or_else(|| self.start_span())
+    }
+
+    /// Erro de token inesperado: `kind` era o token esperado principal, e a lista
+    /// inclui tudo o que o parser testou nesta posição.
+    pub(super) fn expected_token(&self, kind: TokenKind) -> ParseError {
+        let code = if kind == TokenKind::Identifier {
+            ErrorCode::IdentifierExpected
+        } else {
+            ErrorCode::ExpectedToken
+        };
+        let mut error = self.unexpected(code, &format!("{} esperado", describe(kind)));
+        let primary = kind.as_str().to_string();
+        error.expected.retain(|expected| *expected != primary);
+        error.expected.insert(0, primary);
+        error
+    }
+
+    /// Erro de token inesperado quando o esperado é uma construção e não um token
+    /// específico; `what` é o início da mensagem (`"Expressão esperada"`).
+    pub(super) fn unexpected(&self, code: ErrorCode, what: &str) -> ParseError {
+        let (code, found) = match self.peek() {
+            Some(token) => (code, format!("encontrado '{}'", token.value)),
+            None => (ErrorCode::UnexpectedEndOfFile, "mas o arquivo terminou".to_string()),
+        };
+        let mut expected: Vec<String> = self
+            .expected_tokens
+            .at(self.current)
+            .map(|kind| kind.as_str().to_string())
+            .collect();
+        // Os quatro pedaços de template têm o mesmo nome
+        expected.dedup();
+        ParseError {
+            code,
+            message: format!("{}, {}", what, found),
+            span: self.current_span(),
+            expected,
+        }
+    }
+
+    /// Lê um item de uma lista de instruções. Se ele tiver um erro, o erro é
+    /// guardado, os tokens são pulados até um ponto seguro e um nó `Error` ocupa
+    /// o lugar do item, para que o restante do arquivo continue sendo analisado.
+    pub(super) fn parse_or_recover(&mut self, parse: fn(&mut Self) -> Result<Statement, ParseError>) -> Statement {
+        let start = self.start_span();
+        let position = self.current;
+        match parse(self) {
+            Ok(statement) => statement,
+            Err(error) => {
+                // Uma palavra reservada usada como nome (`let class = 1`) não é o
+                // começo de outra instrução
+                if error.code == ErrorCode::ReservedWord && self.current_span().start == error.span.start {
+                    self.advance();
+                }
+                self.errors.push(error);
+                self.synchronize(position);
+                Statement::Error {
+                    span: self.finish_span(start),
+                }
+            }
+        }
+    }
+
+    /// Lê um membro do corpo de uma classe. Se ele tiver um erro, o erro é
+    /// guardado e os tokens são pulados até o próximo `;`, quebra de linha ou `}`,
+    /// para que os outros membros continuem sendo analisados.
+    pub(super) fn parse_class_member_or_recover(&mut self, in_abstract_class: bool) -> Option<ClassMember> {
+        let position = self.current;
+        match self.parse_class_member(in_abstract_class) {
+            Ok(member) => member,
+            Err(error) => {
+                self.errors.push(error);
+                self.synchronize(position);
+                None
+            }
+        }
+    }
+
+    /// Pula tokens até depois de um `;`, até uma quebra de linha, até o início de
+    /// outra instrução ou até o `}` que fecha o bloco de fora. As chaves abertas
+    /// desde `position`, o começo da instrução com erro, são fechadas antes, para
+    /// não sair do bloco errado.
+    fn synchronize(&mut self, position: usize) {
+        // Sempre consome ao menos um token, senão o mesmo erro se repetiria
+        if self.current == position && !self.is_at_end() {
+            self.advance();
+        }
+        let mut depth = 0usize;
+        for token in &self.tokens[position..self.current] {
+            match token.kind {
+                TokenKind::LBrace => depth += 1,
+                TokenKind::RBrace => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+
+        while let Some(kind) = self.peek_kind() {
+            if depth == 0 {
+                match kind {
+                    TokenKind::RBrace => return,
+                    TokenKind::Semicolon => {
+                        self.advance();
+                        return;
+                    }
+                    _ if self.has_newline_before() || starts_statement(kind) => return,
+                    _ => {}
+                }
+            }
+            match kind {
+                TokenKind::LBrace => depth += 1,
+                TokenKind::RBrace => depth -= 1,
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+}
+
+/// Palavras-chave que só aparecem no começo de uma instrução ou declaração.
+fn starts_statement(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Function
+            | TokenKind::Class
+            | TokenKind::Const
+            | TokenKind::Let
+            | TokenKind::Var
+            | TokenKind::If
+            | TokenKind::For
+            | TokenKind::While
+            | TokenKind::Do
+            | TokenKind::Switch
+            | TokenKind::Try
+            | TokenKind::Throw
+            | TokenKind::Return
+            | TokenKind::Break
+            | TokenKind::Continue
+            | TokenKind::Debugger
+            | TokenKind::Import
+            | TokenKind::Export
+            | TokenKind::Interface
+            | TokenKind::Enum
+    )
+}
@@ -0,0 +1,113 @@
+use super::{Expression, Parser};
+use crate::lexer::{unescape, TokenKind};
+use serde::{Deserialize, Serialize};
+
+/// Valor de um literal já interpretado. No JSON vira o próprio valor (`"a\nb"`,
+/// `1.5`, `true`, `null`), e um BigInt vira `{ "bigint": "255" }`, em decimal,
+/// para não ser confundido com uma string. Números que o JSON não representa
+/// (`1e400`) viram `{ "number": "Infinity" }`, e não `null`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LiteralValue {
+    String(String),
+    Number(f64),
+    /// `Infinity` ou `NaN`
+    NonFiniteNumber { number: String },
+    BigInt { bigint: String },
+    Boolean(bool),
+    Null,
+}
+
+impl Parser {
+    /// Lê o literal atual: string, número, BigInt, `true`, `false` ou `null`.
+    pub(super) fn parse_literal(&mut self) -> Expression {
+        let token = self.advance();
+        let raw = token.value.clone();
+        let value = match token.kind {
+            // Escapes inválidos já foram apontados pelo lexer
+            TokenKind::StringLiteral => LiteralValue::String(unescape(string_body(&raw)).0),
+            TokenKind::NumericLiteral => match numeric_value(&raw) {
+                value if value.is_finite() => LiteralValue::Number(value),
+                value => LiteralValue::NonFiniteNumber {
+                    number: if value.is_nan() { "NaN" } else { "Infinity" }.to_string(),
+                },
+            },
+            TokenKind::BigIntLiteral => LiteralValue::BigInt {
+                bigint: bigint_value(&raw),
+            },
+            TokenKind::True => LiteralValue::Boolean(true),
+            TokenKind::False => LiteralValue::Boolean(false),
+            _ => LiteralValue::Null,
+        };
+        Expression::Literal {
+            value,
+            raw,
+            span: self.last_token,
+        }
+    }
+}
+
+/// Conteúdo de uma string sem as aspas. Uma string sem fechamento (o lexer já
+/// apontou o erro) perde só a aspa de abertura; uma aspa final escapada (`"a\"`)
+/// não conta como fechamento.
+fn string_body(raw: &str) -> &str {
+    let mut chars = raw.chars();
+    let Some(quote) = chars.next() else {
+        return raw;
+    };
+    let body = chars.as_str();
+    match body.strip_suffix(quote) {
+        Some(inner) if inner.chars().rev().take_while(|&c| c == '\\').count() % 2 == 0 => inner,
+        _ => body,
+    }
+}
+
+/// Base e dígitos de um número, sem separadores `_` nem o sufixo `n`.
+/// Octais legados (`0755`) têm base 8; o resto que não tem prefixo é decimal.
+fn split_radix(raw: &str) -> (u32, String) {
+    let digits: String = raw.trim_end_matches('n').chars().filter(|&c| c != '_').collect();
+    match digits.get(..2) {
+        Some("0x" | "0X") => (16, digits[2..].to_string()),
+        Some("0o" | "0O") => (8, digits[2..].to_string()),
+        Some("0b" | "0B") => (2, digits[2..].to_string()),
+        _ if digits.len() > 1 && digits.starts_with('0') && digits.bytes().all(|b| (b'0'..=b'7').contains(&b)) => {
+            (8, digits[1..].to_string())
+        }
+        _ => (10, digits),
+    }
+}
+
+fn numeric_value(raw: &str) -> f64 {
+    let (radix, digits) = split_radix(raw);
+    if radix == 10 {
+        // Literais malformados já foram apontados pelo lexer
+        return digits.parse().unwrap_or(f64::NAN);
+    }
+    digits
+        .chars()
+        .filter_map(|c| c.to_digit(radix))
+        .fold(0.0, |value, digit| value * radix as f64 + digit as f64)
+}
+
+/// Valor de um BigInt em decimal, com dígitos em base 10⁹ para não ter limite de tamanho.
+fn bigint_value(raw: &str) -> String {
+    let (radix, digits) = split_radix(raw);
+    // Partes menos significativas primeiro
+    let mut parts: Vec<u32> = vec![0];
+    for digit in digits.chars().filter_map(|c| c.to_digit(radix)) {
+        let mut carry = digit as u64;
+        for part in parts.iter_mut() {
+            let value = *part as u64 * radix as u64 + carry;
+            *part = (value % 1_000_000_000) as u32;
+            carry = value / 1_000_000_000;
+        }
+        if carry > 0 {
+            parts.push(carry as u32);
+        }
+    }
+    let mut text = parts.last().unwrap().to_string();
+    for part in parts.iter().rev().skip(1) {
+        text.push_str(&format!("{:09}", part));
+    }
+    text
+}
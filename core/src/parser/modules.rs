@@ -0,0 +1,540 @@
+use super::{Decorator, ErrorCode, Expression, Identifier, ParseError, Parser, Span, Statement};
+use crate::lexer::TokenKind;
+use serde::{Deserialize, Serialize};
+
+/// Especificador de um `import`: `a`, `* as ns` ou `{ a as b }`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[allow(clippy::enum_variant_names)]
+pub enum ImportSpecifier {
+    ImportDefaultSpecifier {
+        local: Identifier,
+        span: Span,
+    },
+    ImportNamespaceSpecifier {
+        local: Identifier,
+        span: Span,
+    },
+    ImportSpecifier {
+        imported: Box<Expression>, // Identificador ou string (`{ "a-b" as c }`)
+        local: Identifier,
+        import_kind: String, // value ou type (`{ type A }`)
+        span: Span,
+    },
+}
+
+/// `{ a as b }` em um `export`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub struct ExportSpecifier {
+    pub local: Expression,
+    pub exported: Expression,
+    pub export_kind: String,
+    pub span: Span,
+}
+
+/// `with { type: "json" }` (ou o antigo `assert { ... }`)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportAttribute {
+    pub key: Expression,
+    pub value: Expression,
+    pub span: Span,
+}
+
+/// O que vem depois de `export default`: uma declaração (função, classe,
+/// interface) ou uma expressão qualquer.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ExportDefault {
+    Declaration(Box<Statement>),
+    Expression(Box<Expression>),
+}
+
+/// Lado direito de `import x = ...`: `require("m")` ou um nome qualificado.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ModuleReference {
+    TSExternalModuleReference {
+        expression: Expression,
+        span: Span,
+    },
+    #[serde(untagged)]
+    EntityName(Expression),
+}
+
+impl Parser {
+    /// Declarações que só podem aparecer no nível superior do arquivo.
+    pub(super) fn parse_module_item(&mut self) -> Result<Statement, ParseError> {
+        let start = self.start_span();
+        match self.peek_kind() {
+            Some(TokenKind::Import) if !matches!(self.peek_next_kind(), Some(TokenKind::LParen | TokenKind::Dot)) => {
+                self.advance();
+                self.parse_import_declaration(start, false)
+            }
+            Some(TokenKind::Export) => {
+                self.advance();
+                self.parse_export_declaration(start, Vec::new())
+            }
+            // `@dec export class A {}` ou só `@dec class A {}`
+            Some(TokenKind::At) => {
+                let decorators = self.parse_decorators()?;
+                if self.match_token(TokenKind::Export) {
+                    return self.parse_export_declaration(start, decorators);
+                }
+                Ok(Statement::ClassDeclaration(self.parse_class_declaration(start, decorators, true)?))
+            }
+            _ => self.parse_statement(),
+        }
+    }
+
+    /// Lê o restante de um `import`, depois da palavra-chave.
+    fn parse_import_declaration(&mut self, start: Span, is_export: bool) -> Result<Statement, ParseError> {
+        // `import type X from`, mas não `import type from "m"` nem `import type = ...`
+        let import_kind = if self.check(TokenKind::Type)
+            && !matches!(self.peek_next_kind(), Some(TokenKind::From | TokenKind::Eq | TokenKind::Comma))
+        {
+            self.advance();
+            "type"
+        } else {
+            "value"
+        };
+
+        if self.check(TokenKind::StringLiteral) && import_kind == "value" {
+            let source = self.parse_module_source()?;
+            let attributes = self.parse_import_attributes()?;
+            self.consume_semicolon()?;
+            return Ok(Statement::ImportDeclaration {
+                specifiers: Vec::new(),
+                source,
+                import_kind: import_kind.to_string(),
+                attributes,
+                span: self.finish_span(start),
+            });
+        }
+
+        let mut specifiers = Vec::new();
+        if !matches!(self.peek_kind(), Some(TokenKind::Star | TokenKind::LBrace)) {
+            let local = self.parse_identifier()?;
+            if self.match_token(TokenKind::Eq) {
+                return self.parse_import_equals_declaration(start, local, import_kind, is_export);
+            }
+            let span = local.span;
+            specifiers.push(ImportSpecifier::ImportDefaultSpecifier { local, span });
+            if !self.match_token(TokenKind::Comma) {
+                return self.finish_import_declaration(start, specifiers, import_kind);
+            }
+        }
+        if is_export {
+            return Err(ParseError::new(
+                ErrorCode::InvalidModuleDeclaration,
+                self.current_span(),
+                "`export import` só aceita a forma `export import x = ...`",
+            ));
+        }
+
+        let namespace_start = self.start_span();
+        if self.match_token(TokenKind::Star) {
+            self.consume(TokenKind::As)?;
+            let local = self.parse_identifier()?;
+            specifiers.push(ImportSpecifier::ImportNamespaceSpecifier {
+                local,
+                span: self.finish_span(namespace_start),
+            });
+        } else {
+            self.consume(TokenKind::LBrace)?;
+            while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+                specifiers.push(self.parse_import_specifier()?);
+                if !self.match_token(TokenKind::Comma) {
+                    break;
+                }
+            }
+            self.consume(TokenKind::RBrace)?;
+        }
+
+        self.finish_import_declaration(start, specifiers, import_kind)
+    }
+
+    fn finish_import_declaration(
+        &mut self,
+        start: Span,
+        specifiers: Vec<ImportSpecifier>,
+        import_kind: &str,
+    ) -> Result<Statement, ParseError> {
+        if import_kind == "type"
+            && specifiers.len() > 1
+            && matches!(specifiers[0], ImportSpecifier::ImportDefaultSpecifier { .. })
+        {
+            return Err(ParseError::new(
+                ErrorCode::InvalidModuleDeclaration,
+                self.finish_span(start),
+                "Um `import type` não pode ter importação padrão e nomeada ao mesmo tempo",
+            ));
+        }
+
+        self.consume(TokenKind::From)?;
+        let source = self.parse_module_source()?;
+        let attributes = self.parse_import_attributes()?;
+        self.consume_semicolon()?;
+
+        Ok(Statement::ImportDeclaration {
+            specifiers,
+            source,
+            import_kind: import_kind.to_string(),
+            attributes,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_import_specifier(&mut self) -> Result<ImportSpecifier, ParseError> {
+        let start = self.start_span();
+        let import_kind = if self.is_inline_type_modifier() {
+            self.advance();
+            "type"
+        } else {
+            "value"
+        };
+
+        let binding_name = self.check_identifier();
+        let imported = self.parse_module_export_name()?;
+        let local = if self.match_token(TokenKind::As) {
+            self.parse_identifier()?
+        } else {
+            match &imported {
+                // `import { default }` precisaria de uma variável chamada `default`
+                Expression::Identifier(identifier) if !binding_name => {
+                    return Err(ParseError::new(
+                        ErrorCode::ReservedWord,
+                        identifier.span,
+                        format!(
+                            "'{}' é uma palavra reservada; dê um nome local com `as`",
+                            identifier.name
+                        ),
+                    ))
+                }
+                Expression::Identifier(identifier) => Identifier {
+                    name: identifier.name.clone(),
+                    span: identifier.span,
+                },
+                _ => {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidModuleDeclaration,
+                        self.last_token,
+                        "Uma importação por string precisa de um nome local (`as`)",
+                    ))
+                }
+            }
+        };
+
+        Ok(ImportSpecifier::ImportSpecifier {
+            imported: Box::new(imported),
+            local,
+            import_kind: import_kind.to_string(),
+            span: self.finish_span(start),
+        })
+    }
+
+    /// `import x = require("m")` ou `import x = ns.y`, depois do `=`.
+    fn parse_import_equals_declaration(
+        &mut self,
+        start: Span,
+        id: Identifier,
+        import_kind: &str,
+        is_export: bool,
+    ) -> Result<Statement, ParseError> {
+        let reference_start = self.start_span();
+        let module_reference = if self.check(TokenKind::Require) && self.peek_next_kind() == Some(TokenKind::LParen) {
+            self.advance();
+            self.advance();
+            let expression = self.parse_module_source()?;
+            self.consume(TokenKind::RParen)?;
+            ModuleReference::TSExternalModuleReference {
+                expression,
+                span: self.finish_span(reference_start),
+            }
+        } else {
+            ModuleReference::EntityName(self.parse_entity_name()?)
+        };
+        self.consume_semicolon()?;
+
+        Ok(Statement::TSImportEqualsDeclaration {
+            id,
+            module_reference,
+            import_kind: import_kind.to_string(),
+            is_export,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Lê o restante de um `export`, depois da palavra-chave. `decorators` são os
+    /// que vieram antes do `export`, e só valem para uma classe.
+    fn parse_export_declaration(&mut self, start: Span, decorators: Vec<Decorator>) -> Result<Statement, ParseError> {
+        match self.peek_kind() {
+            Some(TokenKind::Default) => {
+                self.advance();
+                self.parse_export_default_declaration(start, decorators)
+            }
+            _ if !decorators.is_empty() => {
+                let class = self.parse_class_declaration(self.start_span(), decorators, true)?;
+                Ok(Statement::ExportNamedDeclaration {
+                    declaration: Some(Box::new(Statement::ClassDeclaration(class))),
+                    specifiers: Vec::new(),
+                    source: None,
+                    export_kind: "value".to_string(),
+                    attributes: Vec::new(),
+                    span: self.finish_span(start),
+                })
+            }
+            Some(TokenKind::Eq) => {
+                self.advance();
+                let expression = self.parse_assignment()?;
+                self.consume_semicolon()?;
+                Ok(Statement::TSExportAssignment {
+                    expression,
+                    span: self.finish_span(start),
+                })
+            }
+            Some(TokenKind::Import) => {
+                self.advance();
+                self.parse_import_declaration(start, true)
+            }
+            Some(TokenKind::Star) => {
+                self.parse_export_all_declaration(start, "value")
+            }
+            Some(TokenKind::Type) if self.peek_next_kind() == Some(TokenKind::Star) => {
+                self.advance();
+                self.parse_export_all_declaration(start, "type")
+            }
+            Some(TokenKind::LBrace) => self.parse_export_specifiers(start, "value"),
+            Some(TokenKind::Type) if self.peek_next_kind() == Some(TokenKind::LBrace) => {
+                self.advance();
+                self.parse_export_specifiers(start, "type")
+            }
+            _ => {
+                if !self.is_start_of_declaration() {
+                    return Err(self.unexpected(ErrorCode::DeclarationExpected, "Declaração esperada"));
+                }
+                let declaration = self.parse_statement()?;
+                Ok(Statement::ExportNamedDeclaration {
+                    declaration: Some(Box::new(declaration)),
+                    specifiers: Vec::new(),
+                    source: None,
+                    export_kind: "value".to_string(),
+                    attributes: Vec::new(),
+                    span: self.finish_span(start),
+                })
+            }
+        }
+    }
+
+    fn parse_export_default_declaration(&mut self, start: Span, decorators: Vec<Decorator>) -> Result<Statement, ParseError> {
+        let declaration_start = self.start_span();
+        let declaration = match self.peek_kind() {
+            _ if !decorators.is_empty() || self.check(TokenKind::At) || self.check(TokenKind::Class) => {
+                let class = self.parse_class_declaration(declaration_start, decorators, false)?;
+                ExportDefault::Declaration(Box::new(Statement::ClassDeclaration(class)))
+            }
+            Some(TokenKind::Abstract) if self.peek_next_kind() == Some(TokenKind::Class) => {
+                let class = self.parse_class_declaration(declaration_start, Vec::new(), false)?;
+                ExportDefault::Declaration(Box::new(Statement::ClassDeclaration(class)))
+            }
+            Some(TokenKind::Function) => {
+                self.advance();
+                ExportDefault::Declaration(Box::new(self.parse_function_declaration(declaration_start, false, false)?))
+            }
+            Some(TokenKind::Identifier)
+                if self.check_contextual("async") && self.peek_next_kind() == Some(TokenKind::Function) =>
+            {
+                self.advance();
+                self.advance();
+                ExportDefault::Declaration(Box::new(self.parse_function_declaration(declaration_start, false, true)?))
+            }
+            Some(TokenKind::Interface) if self.peek_next_kind().is_some_and(TokenKind::can_be_identifier) => {
+                ExportDefault::Declaration(Box::new(self.parse_statement()?))
+            }
+            _ => {
+                let expression = self.parse_assignment()?;
+                self.consume_semicolon()?;
+                ExportDefault::Expression(Box::new(expression))
+            }
+        };
+
+        Ok(Statement::ExportDefaultDeclaration {
+            declaration,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// `export * from "m"` e `export * as ns from "m"`, a partir do `*`.
+    fn parse_export_all_declaration(&mut self, start: Span, export_kind: &str) -> Result<Statement, ParseError> {
+        self.consume(TokenKind::Star)?;
+        let exported = if self.match_token(TokenKind::As) {
+            Some(self.parse_module_export_name()?)
+        } else {
+            None
+        };
+        self.consume(TokenKind::From)?;
+        let source = self.parse_module_source()?;
+        let attributes = self.parse_import_attributes()?;
+        self.consume_semicolon()?;
+
+        Ok(Statement::ExportAllDeclaration {
+            exported,
+            source,
+            export_kind: export_kind.to_string(),
+            attributes,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// `export { a, b as c }` com ou sem `from "m"`.
+    fn parse_export_specifiers(&mut self, start: Span, export_kind: &str) -> Result<Statement, ParseError> {
+        self.consume(TokenKind::LBrace)?;
+        let mut specifiers = Vec::new();
+        while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+            let specifier_start = self.start_span();
+            let specifier_kind = if self.is_inline_type_modifier() {
+                self.advance();
+                "type"
+            } else {
+                "value"
+            };
+            let local = self.parse_module_export_name()?;
+            let exported = if self.match_token(TokenKind::As) {
+                self.parse_module_export_name()?
+            } else {
+                match &local {
+                    Expression::Identifier(identifier) => Expression::Identifier(Identifier {
+                        name: identifier.name.clone(),
+                        span: identifier.span,
+                    }),
+                    Expression::Literal { value, raw, span } => Expression::Literal {
+                        value: value.clone(),
+                        raw: raw.clone(),
+                        span: *span,
+                    },
+                    _ => unreachable!("parse_module_export_name só produz identificadores e strings"),
+                }
+            };
+            specifiers.push(ExportSpecifier {
+                local,
+                exported,
+                export_kind: specifier_kind.to_string(),
+                span: self.finish_span(specifier_start),
+            });
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+        }
+        self.consume(TokenKind::RBrace)?;
+
+        let (source, attributes) = if self.match_token(TokenKind::From) {
+            (Some(self.parse_module_source()?), self.parse_import_attributes()?)
+        } else {
+            // Sem `from`, cada nome local precisa ser uma referência a algo deste arquivo
+            if let Some(specifier) = specifiers.iter().find(|s| matches!(s.local, Expression::Literal { .. })) {
+                return Err(ParseError::new(
+                    ErrorCode::InvalidModuleDeclaration,
+                    specifier.span,
+                    "Um nome em string só pode ser reexportado com `from`",
+                ));
+            }
+            (None, Vec::new())
+        };
+        self.consume_semicolon()?;
+
+        Ok(Statement::ExportNamedDeclaration {
+            declaration: None,
+            specifiers,
+            source,
+            export_kind: export_kind.to_string(),
+            attributes,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Declarações que podem vir depois de `export`.
+    fn is_start_of_declaration(&self) -> bool {
+        match self.peek_kind() {
+            Some(
+                TokenKind::Function
+                | TokenKind::Class
+                | TokenKind::At
+                | TokenKind::Const
+                | TokenKind::Let
+                | TokenKind::Var
+                | TokenKind::Enum,
+            ) => {
+                true
+            }
+            Some(TokenKind::Abstract) => self.peek_next_kind() == Some(TokenKind::Class),
+            Some(TokenKind::Declare) => {
+                matches!(self.peek_next_kind(), Some(TokenKind::Const | TokenKind::Let | TokenKind::Var))
+            }
+            Some(TokenKind::Identifier) => {
+                self.check_contextual("async") && self.peek_next_kind() == Some(TokenKind::Function)
+            }
+            Some(TokenKind::Interface | TokenKind::Type) => self.peek_next_kind().is_some_and(TokenKind::can_be_identifier),
+            _ => false,
+        }
+    }
+
+    /// `{ type A }` marca só um especificador como importação de tipo. Em
+    /// `{ type }`, `{ type as x }` e `{ type as as }`, `type` é o próprio nome; já
+    /// `{ type as }` e `{ type as as x }` importam `as` só como tipo.
+    fn is_inline_type_modifier(&self) -> bool {
+        if !self.check(TokenKind::Type) {
+            return false;
+        }
+        match self.peek_next_kind() {
+            None | Some(TokenKind::Comma | TokenKind::RBrace) => false,
+            Some(TokenKind::As) => match self.peek_kind_at(2) {
+                Some(TokenKind::Comma | TokenKind::RBrace) => true,
+                Some(TokenKind::As) => !matches!(self.peek_kind_at(3), None | Some(TokenKind::Comma | TokenKind::RBrace)),
+                _ => false,
+            },
+            _ => true,
+        }
+    }
+
+    /// Nome exportado de um módulo: qualquer identificador (inclusive palavras
+    /// reservadas, como `default`) ou uma string.
+    fn parse_module_export_name(&mut self) -> Result<Expression, ParseError> {
+        if self.check(TokenKind::StringLiteral) {
+            return Ok(self.parse_literal());
+        }
+        Ok(Expression::Identifier(self.parse_property_identifier()?))
+    }
+
+    fn parse_module_source(&mut self) -> Result<Expression, ParseError> {
+        if !self.check(TokenKind::StringLiteral) {
+            return Err(self.expected_token(TokenKind::StringLiteral));
+        }
+        Ok(self.parse_literal())
+    }
+
+    fn parse_import_attributes(&mut self) -> Result<Vec<ImportAttribute>, ParseError> {
+        if !self.check(TokenKind::With) && !self.check_contextual("assert") {
+            return Ok(Vec::new());
+        }
+        self.advance();
+
+        self.consume(TokenKind::LBrace)?;
+        let mut attributes = Vec::new();
+        while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+            let start = self.start_span();
+            let key = self.parse_module_export_name()?;
+            self.consume(TokenKind::Colon)?;
+            let value = self.parse_module_source()?;
+            attributes.push(ImportAttribute {
+                key,
+                value,
+                span: self.finish_span(start),
+            });
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+        }
+        self.consume(TokenKind::RBrace)?;
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,314 @@
+use super::{ErrorCode, Expression, Identifier, ObjectProperty, ParseError, Parser, Span};
+use crate::lexer::TokenKind;
+use serde::{Deserialize, Serialize};
+
+/// Alvo de uma ligação ou atribuição: `x`, `{ a, b: c }`, `[x, , ...rest]` ou `x = padrão`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[allow(clippy::enum_variant_names)]
+pub enum Pattern {
+    Identifier(Identifier),
+    ObjectPattern {
+        properties: Vec<PatternProperty>,
+        span: Span,
+    },
+    ArrayPattern {
+        elements: Vec<Option<Pattern>>, // `None` nos buracos (`[, b]`)
+        span: Span,
+    },
+    RestElement {
+        argument: Box<Pattern>,
+        span: Span,
+    },
+    AssignmentPattern {
+        left: Box<Pattern>,
+        right: Box<Expression>,
+        span: Span,
+    },
+    /// Acesso a propriedade como alvo de atribuição (`[a.b, c[0]] = x`)
+    #[serde(untagged)]
+    Expression(Box<Expression>),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PatternProperty {
+    Property {
+        key: Box<Expression>,
+        value: Pattern,
+        computed: bool,
+        shorthand: bool, // `{ a }` ou `{ a = 1 }`
+        span: Span,
+    },
+    RestElement {
+        argument: Box<Pattern>,
+        span: Span,
+    },
+}
+
+impl Parser {
+    /// Identificador ou desestruturação, sem valor padrão, em declarações e parâmetros.
+    pub(super) fn parse_binding_pattern(&mut self) -> Result<Pattern, ParseError> {
+        match self.peek_kind() {
+            Some(TokenKind::LBrace) => self.parse_object_pattern(),
+            Some(TokenKind::LBracket) => self.parse_array_pattern(),
+            _ => Ok(Pattern::Identifier(self.parse_identifier()?)),
+        }
+    }
+
+    /// Elemento de uma desestruturação: um alvo seguido de um `= padrão` opcional.
+    fn parse_pattern_element(&mut self) -> Result<Pattern, ParseError> {
+        let start = self.start_span();
+        let target = self.parse_binding_pattern()?;
+        self.parse_pattern_default(start, target)
+    }
+
+    /// `alvo = padrão`, com `start` no começo do alvo.
+    pub(super) fn parse_pattern_default(&mut self, start: Span, target: Pattern) -> Result<Pattern, ParseError> {
+        if !self.match_token(TokenKind::Eq) {
+            return Ok(target);
+        }
+        let right = self.parse_assignment()?;
+        Ok(Pattern::AssignmentPattern {
+            left: Box::new(target),
+            right: Box::new(right),
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Lê `...alvo` e devolve o alvo. O resto precisa ser o último elemento antes de `closing`.
+    fn parse_rest_argument(&mut self, closing: TokenKind) -> Result<Pattern, ParseError> {
+        self.consume(TokenKind::Ellipsis)?;
+        let argument = self.parse_binding_pattern()?;
+        if self.check(TokenKind::Eq) {
+            return Err(ParseError::new(
+                ErrorCode::InvalidRestElement,
+                self.current_span(),
+                "Um elemento rest não pode ter valor padrão",
+            ));
+        }
+        if self.check(TokenKind::Comma) {
+            return Err(ParseError::new(
+                ErrorCode::InvalidRestElement,
+                self.current_span(),
+                "Um elemento rest deve ser o último da lista",
+            ));
+        }
+        if !self.check(closing) {
+            return Err(self.expected_token(closing));
+        }
+        Ok(argument)
+    }
+
+    fn parse_object_pattern(&mut self) -> Result<Pattern, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::LBrace)?;
+        let mut properties = Vec::new();
+
+        while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+            let property_start = self.start_span();
+            if self.check(TokenKind::Ellipsis) {
+                let argument = self.parse_rest_argument(TokenKind::RBrace)?;
+                // Em objetos, o resto não pode ser outra desestruturação
+                if !matches!(argument, Pattern::Identifier(_) | Pattern::Expression(_)) {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidRestElement,
+                        self.finish_span(property_start),
+                        "O rest de uma desestruturação de objeto não pode ser outra desestruturação",
+                    ));
+                }
+                properties.push(PatternProperty::RestElement {
+                    argument: Box::new(argument),
+                    span: self.finish_span(property_start),
+                });
+                break;
+            }
+
+            let is_identifier = self.check_identifier();
+            let (key, computed) = self.parse_property_name()?;
+            let (value, shorthand) = if self.match_token(TokenKind::Colon) {
+                (self.parse_pattern_element()?, false)
+            } else {
+                let Expression::Identifier(Identifier { name, span }) = &key else {
+                    return Err(self.expected_token(TokenKind::Colon));
+                };
+                if !is_identifier || computed {
+                    return Err(ParseError::new(
+                        ErrorCode::InvalidBindingName,
+                        *span,
+                        format!("'{}' não pode ser usado como nome de variável", name),
+                    ));
+                }
+                let target = Pattern::Identifier(Identifier {
+                    name: name.clone(),
+                    span: *span,
+                });
+                (self.parse_pattern_default(property_start, target)?, true)
+            };
+            properties.push(PatternProperty::Property {
+                key: Box::new(key),
+                value,
+                computed,
+                shorthand,
+                span: self.finish_span(property_start),
+            });
+
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+        }
+
+        self.consume(TokenKind::RBrace)?;
+        Ok(Pattern::ObjectPattern {
+            properties,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_array_pattern(&mut self) -> Result<Pattern, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::LBracket)?;
+        let mut elements = Vec::new();
+
+        while !self.check(TokenKind::RBracket) && !self.is_at_end() {
+            if self.match_token(TokenKind::Comma) {
+                elements.push(None);
+                continue;
+            }
+            if self.check(TokenKind::Ellipsis) {
+                let rest_start = self.start_span();
+                let argument = self.parse_rest_argument(TokenKind::RBracket)?;
+                elements.push(Some(Pattern::RestElement {
+                    argument: Box::new(argument),
+                    span: self.finish_span(rest_start),
+                }));
+                break;
+            }
+            elements.push(Some(self.parse_pattern_element()?));
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+        }
+
+        self.consume(TokenKind::RBracket)?;
+        Ok(Pattern::ArrayPattern {
+            elements,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Converte um array ou objeto literal já lido no lado esquerdo de `[a, b] = ...`
+    /// (ou de um `for-of`) em desestruturação. Os alvos também podem ser
+    /// propriedades (`obj.x`, `arr[0]`).
+    pub(super) fn expression_to_pattern(&self, expression: Expression) -> Result<Pattern, ParseError> {
+        match expression {
+            Expression::ArrayExpression { elements, span } => {
+                let count = elements.len();
+                let mut patterns = Vec::with_capacity(count);
+                for (index, element) in elements.into_iter().enumerate() {
+                    let pattern = match element {
+                        Some(Expression::SpreadElement { argument, span }) => {
+                            if index + 1 < count || self.spreads_before_comma.contains(&span.start) {
+                                return Err(ParseError::new(
+                                    ErrorCode::InvalidRestElement,
+                                    span,
+                                    "Um elemento rest deve ser o último da lista",
+                                ));
+                            }
+                            Some(Pattern::RestElement {
+                                argument: Box::new(self.rest_argument_to_pattern(*argument)?),
+                                span,
+                            })
+                        }
+                        Some(element) => Some(self.element_to_pattern(element)?),
+                        None => None,
+                    };
+                    patterns.push(pattern);
+                }
+                Ok(Pattern::ArrayPattern { elements: patterns, span })
+            }
+            Expression::ObjectExpression { properties, span } => {
+                let count = properties.len();
+                let mut patterns = Vec::with_capacity(count);
+                for (index, property) in properties.into_iter().enumerate() {
+                    let pattern = match property {
+                        ObjectProperty::SpreadElement { argument, span } => {
+                            if index + 1 < count {
+                                return Err(ParseError::new(
+                                    ErrorCode::InvalidRestElement,
+                                    span,
+                                    "Um elemento rest deve ser o último da lista",
+                                ));
+                            }
+                            let argument = self.rest_argument_to_pattern(argument)?;
+                            // Em objetos, o resto não pode ser outra desestruturação
+                            if !matches!(argument, Pattern::Identifier(_) | Pattern::Expression(_)) {
+                                return Err(ParseError::new(
+                                    ErrorCode::InvalidRestElement,
+                                    span,
+                                    "O rest de uma desestruturação de objeto não pode ser outra desestruturação",
+                                ));
+                            }
+                            PatternProperty::RestElement {
+                                argument: Box::new(argument),
+                                span,
+                            }
+                        }
+                        ObjectProperty::Property {
+                            key,
+                            value,
+                            computed,
+                            shorthand,
+                            method: false,
+                            kind,
+                            span,
+                        } if kind == "init" => PatternProperty::Property {
+                            key,
+                            value: self.element_to_pattern(value)?,
+                            computed,
+                            shorthand,
+                            span,
+                        },
+                        ObjectProperty::Property { span, .. } => {
+                            return Err(ParseError::new(
+                                ErrorCode::InvalidAssignmentTarget,
+                                span,
+                                "Métodos e acessores não podem fazer parte de uma desestruturação",
+                            ));
+                        }
+                    };
+                    patterns.push(pattern);
+                }
+                Ok(Pattern::ObjectPattern {
+                    properties: patterns,
+                    span,
+                })
+            }
+            _ => self.expression_to_simple_target(expression),
+        }
+    }
+
+    /// Elemento de array ou valor de propriedade: um alvo, talvez com `= padrão`.
+    fn element_to_pattern(&self, expression: Expression) -> Result<Pattern, ParseError> {
+        match expression {
+            Expression::AssignmentExpression {
+                operator,
+                left,
+                right,
+                span,
+            } if operator == "=" => Ok(Pattern::AssignmentPattern { left, right, span }),
+            expression => self.expression_to_pattern(expression),
+        }
+    }
+
+    fn rest_argument_to_pattern(&self, argument: Expression) -> Result<Pattern, ParseError> {
+        if let Expression::AssignmentExpression { span, .. } = argument {
+            return Err(ParseError::new(
+                ErrorCode::InvalidRestElement,
+                span,
+                "Um elemento rest não pode ter valor padrão",
+            ));
+        }
+        self.expression_to_pattern(argument)
+    }
+}
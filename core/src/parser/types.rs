@@ -0,0 +1,899 @@
+use super::{ErrorCode, Expression, Identifier, Param, ParseError, Parser, Pattern, Span, TemplateElement};
+use crate::lexer::TokenKind;
+use serde::{Deserialize, Serialize};
+
+/// Árvore de uma anotação de tipo do TypeScript.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[allow(clippy::enum_variant_names)]
+pub enum TypeNode {
+    /// `any`, `unknown`, `number`, `string`, `boolean`, `bigint`, `symbol`,
+    /// `object`, `never`, `void`, `undefined` e `null`
+    TSKeywordType {
+        keyword: String,
+        span: Span,
+    },
+    TSThisType {
+        span: Span,
+    },
+    /// `const` em `x as const` e `<const>x`, que não é um tipo de verdade
+    TSConstType {
+        span: Span,
+    },
+    TSTypeReference {
+        type_name: Expression,
+        type_arguments: Option<Vec<TypeNode>>,
+        span: Span,
+    },
+    TSUnionType {
+        types: Vec<TypeNode>,
+        span: Span,
+    },
+    TSIntersectionType {
+        types: Vec<TypeNode>,
+        span: Span,
+    },
+    TSArrayType {
+        element_type: Box<TypeNode>,
+        span: Span,
+    },
+    TSTupleType {
+        element_types: Vec<TypeNode>,
+        span: Span,
+    },
+    /// `[nome: T]` dentro de uma tupla
+    TSNamedTupleMember {
+        label: Identifier,
+        optional: bool,
+        element_type: Box<TypeNode>,
+        span: Span,
+    },
+    /// `T?` dentro de uma tupla
+    TSOptionalType {
+        type_annotation: Box<TypeNode>,
+        span: Span,
+    },
+    /// `...T` dentro de uma tupla
+    TSRestType {
+        type_annotation: Box<TypeNode>,
+        span: Span,
+    },
+    TSFunctionType {
+        type_parameters: Option<Vec<TypeParameter>>,
+        params: Vec<Param>,
+        return_type: Box<TypeNode>,
+        span: Span,
+    },
+    TSConstructorType {
+        r#abstract: bool,
+        type_parameters: Option<Vec<TypeParameter>>,
+        params: Vec<Param>,
+        return_type: Box<TypeNode>,
+        span: Span,
+    },
+    TSTypeLiteral {
+        members: Vec<TypeMember>,
+        span: Span,
+    },
+    /// `"a"`, `42`, `-1`, `10n`, `true`
+    TSLiteralType {
+        literal: Expression,
+        span: Span,
+    },
+    TSTemplateLiteralType {
+        quasis: Vec<TemplateElement>,
+        types: Vec<TypeNode>,
+        span: Span,
+    },
+    /// `keyof T`, `unique symbol`, `readonly T[]`
+    TSTypeOperator {
+        operator: String,
+        type_annotation: Box<TypeNode>,
+        span: Span,
+    },
+    /// `typeof x.y` e `typeof import("m")`
+    TSTypeQuery {
+        expr_name: TypeQueryName,
+        span: Span,
+    },
+    /// `import("m")`, `import("m").A.B<T>`
+    TSImportType {
+        argument: Box<Expression>,
+        qualifier: Option<Box<Expression>>,
+        type_arguments: Option<Vec<TypeNode>>,
+        span: Span,
+    },
+    TSIndexedAccessType {
+        object_type: Box<TypeNode>,
+        index_type: Box<TypeNode>,
+        span: Span,
+    },
+    TSConditionalType {
+        check_type: Box<TypeNode>,
+        extends_type: Box<TypeNode>,
+        true_type: Box<TypeNode>,
+        false_type: Box<TypeNode>,
+        span: Span,
+    },
+    TSInferType {
+        name: Identifier,
+        constraint: Option<Box<TypeNode>>,
+        span: Span,
+    },
+    /// `{ readonly [K in keyof T as N]?: T[K] }`
+    TSMappedType {
+        readonly: Option<String>, // "+", "-" ou "true"
+        key: Identifier,
+        constraint: Box<TypeNode>,
+        name_type: Option<Box<TypeNode>>,
+        optional: Option<String>, // "+", "-" ou "true"
+        type_annotation: Option<Box<TypeNode>>,
+        span: Span,
+    },
+    /// `x is T`, `asserts x`, `asserts this is T` em tipos de retorno
+    TSTypePredicate {
+        asserts: bool,
+        parameter_name: Identifier,
+        type_annotation: Option<Box<TypeNode>>,
+        span: Span,
+    },
+}
+
+/// Operando de `typeof` em um tipo: um nome (`x.y`) ou um `import("m")`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TypeQueryName {
+    Name(Expression),
+    Import(Box<TypeNode>),
+}
+
+/// `T extends C = D` em `type A<T> = ...`, `function f<T>()`, `class C<T>`...
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TypeParameter {
+    pub name: Identifier,
+    pub constraint: Option<TypeNode>,
+    pub default: Option<TypeNode>,
+    pub span: Span,
+}
+
+/// Membro de um tipo objeto (`{ ... }`) ou de uma interface.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[allow(clippy::enum_variant_names)]
+pub enum TypeMember {
+    TSPropertySignature {
+        key: Expression,
+        computed: bool,
+        optional: bool,
+        readonly: bool,
+        type_annotation: Option<TypeNode>,
+        span: Span,
+    },
+    TSMethodSignature {
+        key: Expression,
+        computed: bool,
+        optional: bool,
+        kind: String, // method, get, set
+        type_parameters: Option<Vec<TypeParameter>>,
+        params: Vec<Param>,
+        return_type: Option<TypeNode>,
+        span: Span,
+    },
+    /// `(x: T): R`
+    TSCallSignatureDeclaration {
+        type_parameters: Option<Vec<TypeParameter>>,
+        params: Vec<Param>,
+        return_type: Option<TypeNode>,
+        span: Span,
+    },
+    /// `new (x: T): R`
+    TSConstructSignatureDeclaration {
+        type_parameters: Option<Vec<TypeParameter>>,
+        params: Vec<Param>,
+        return_type: Option<TypeNode>,
+        span: Span,
+    },
+    TSIndexSignature {
+        parameters: Vec<Param>,
+        type_annotation: Option<TypeNode>,
+        readonly: bool,
+        span: Span,
+    },
+}
+
+impl Parser {
+    /// Lê o tipo depois de um `:` de anotação.
+    pub(super) fn parse_type_annotation(&mut self) -> Result<TypeNode, ParseError> {
+        self.parse_type()
+    }
+
+    /// Tipo de retorno: além de um tipo comum, aceita predicados (`x is T`,
+    /// `asserts x`, `asserts x is T`).
+    pub(super) fn parse_return_type(&mut self) -> Result<TypeNode, ParseError> {
+        let asserts = self.check(TokenKind::Asserts)
+            && matches!(self.peek_next_kind(), Some(TokenKind::Identifier | TokenKind::This));
+        let is_predicate = matches!(self.peek_kind(), Some(TokenKind::Identifier | TokenKind::This))
+            && self.peek_next_kind() == Some(TokenKind::Is);
+        if !asserts && !is_predicate {
+            return self.parse_type();
+        }
+
+        let start = self.start_span();
+        if asserts {
+            self.advance();
+        }
+        let name = self.advance().value.clone();
+        let parameter_name = Identifier {
+            name,
+            span: self.last_token,
+        };
+        let type_annotation = if self.match_token(TokenKind::Is) {
+            Some(Box::new(self.parse_type()?))
+        } else {
+            None
+        };
+        Ok(TypeNode::TSTypePredicate {
+            asserts,
+            parameter_name,
+            type_annotation,
+            span: self.finish_span(start),
+        })
+    }
+
+    pub(super) fn parse_type(&mut self) -> Result<TypeNode, ParseError> {
+        if self.is_start_of_function_type() {
+            return self.parse_function_type();
+        }
+
+        let start = self.start_span();
+        let check_type = self.parse_union_type()?;
+        if !self.in_conditional_extends && self.match_token(TokenKind::Extends) {
+            // Em `A extends B ? ...`, o `B` não pode ser outro condicional sem parênteses
+            let outer = std::mem::replace(&mut self.in_conditional_extends, true);
+            let extends_type = self.parse_type();
+            self.in_conditional_extends = outer;
+            let extends_type = extends_type?;
+
+            self.consume(TokenKind::Question)?;
+            let true_type = self.parse_type_outside_extends()?;
+            self.consume(TokenKind::Colon)?;
+            let false_type = self.parse_type_outside_extends()?;
+            return Ok(TypeNode::TSConditionalType {
+                check_type: Box::new(check_type),
+                extends_type: Box::new(extends_type),
+                true_type: Box::new(true_type),
+                false_type: Box::new(false_type),
+                span: self.finish_span(start),
+            });
+        }
+
+        Ok(check_type)
+    }
+
+    /// Tipos entre delimitadores (`<...>`, `(...)`, `[...]`, `{...}`) voltam a
+    /// aceitar condicionais mesmo dentro do `extends` de outro condicional.
+    fn parse_type_outside_extends(&mut self) -> Result<TypeNode, ParseError> {
+        let outer = std::mem::replace(&mut self.in_conditional_extends, false);
+        let result = self.parse_type();
+        self.in_conditional_extends = outer;
+        result
+    }
+
+    fn parse_union_type(&mut self) -> Result<TypeNode, ParseError> {
+        let start = self.start_span();
+        // `| A | B` também é válido (comum em tipos quebrados em várias linhas)
+        self.match_token(TokenKind::Pipe);
+        let first = self.parse_intersection_type()?;
+        if !self.check(TokenKind::Pipe) {
+            return Ok(first);
+        }
+
+        let mut types = vec![first];
+        while self.match_token(TokenKind::Pipe) {
+            types.push(self.parse_intersection_type()?);
+        }
+        Ok(TypeNode::TSUnionType {
+            types,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_intersection_type(&mut self) -> Result<TypeNode, ParseError> {
+        let start = self.start_span();
+        self.match_token(TokenKind::Amp);
+        let first = self.parse_type_operator()?;
+        if !self.check(TokenKind::Amp) {
+            return Ok(first);
+        }
+
+        let mut types = vec![first];
+        while self.match_token(TokenKind::Amp) {
+            types.push(self.parse_type_operator()?);
+        }
+        Ok(TypeNode::TSIntersectionType {
+            types,
+            span: self.finish_span(start),
+        })
+    }
+
+    fn parse_type_operator(&mut self) -> Result<TypeNode, ParseError> {
+        let start = self.start_span();
+        match self.peek_kind() {
+            Some(TokenKind::Keyof | TokenKind::Unique | TokenKind::Readonly) => {
+                let operator = self.advance().value.clone();
+                let type_annotation = self.parse_type_operator()?;
+                Ok(TypeNode::TSTypeOperator {
+                    operator,
+                    type_annotation: Box::new(type_annotation),
+                    span: self.finish_span(start),
+                })
+            }
+            Some(TokenKind::Infer) => {
+                self.advance();
+                let name = self.parse_identifier()?;
+                // `infer U extends C`: se depois da restrição vier um `?` (fora do `extends`
+                // de um condicional), o `extends` na verdade abria outro condicional.
+                let constraint = if self.check(TokenKind::Extends) {
+                    let checkpoint = self.checkpoint();
+                    self.advance();
+                    let outer = std::mem::replace(&mut self.in_conditional_extends, true);
+                    let constraint = self.parse_type();
+                    self.in_conditional_extends = outer;
+                    match constraint {
+                        Ok(constraint) if outer || !self.check(TokenKind::Question) => Some(Box::new(constraint)),
+                        _ => {
+                            self.rollback(checkpoint);
+                            None
+                        }
+                    }
+                } else {
+                    None
+                };
+                Ok(TypeNode::TSInferType {
+                    name,
+                    constraint,
+                    span: self.finish_span(start),
+                })
+            }
+            _ => self.parse_postfix_type(),
+        }
+    }
+
+    /// `T[]` e `T[K]`, que se aplicam da esquerda para a direita.
+    fn parse_postfix_type(&mut self) -> Result<TypeNode, ParseError> {
+        let start = self.start_span();
+        let mut node = self.parse_primary_type()?;
+
+        // `T\n[]` não continua o tipo: o `[` começa outra instrução
+        while !self.has_newline_before() && self.match_token(TokenKind::LBracket) {
+            if self.match_token(TokenKind::RBracket) {
+                node = TypeNode::TSArrayType {
+                    element_type: Box::new(node),
+                    span: self.finish_span(start),
+                };
+            } else {
+                let index_type = self.parse_type_outside_extends()?;
+                self.consume(TokenKind::RBracket)?;
+                node = TypeNode::TSIndexedAccessType {
+                    object_type: Box::new(node),
+                    index_type: Box::new(index_type),
+                    span: self.finish_span(start),
+                };
+            }
+        }
+
+        Ok(node)
+    }
+
+    fn parse_primary_type(&mut self) -> Result<TypeNode, ParseError> {
+        let start = self.start_span();
+        match self.peek_kind() {
+            Some(
+                TokenKind::Any
+                | TokenKind::Unknown
+                | TokenKind::Number
+                | TokenKind::String
+                | TokenKind::Boolean
+                | TokenKind::Bigint
+                | TokenKind::Symbol
+                | TokenKind::Object
+                | TokenKind::Never
+                | TokenKind::Void
+                | TokenKind::Undefined
+                | TokenKind::Null,
+            ) if self.peek_next_kind() != Some(TokenKind::Dot) => Ok(TypeNode::TSKeywordType {
+                keyword: self.advance().value.clone(),
+                span: self.last_token,
+            }),
+            Some(TokenKind::This) => {
+                self.advance();
+                Ok(TypeNode::TSThisType { span: self.last_token })
+            }
+            Some(
+                TokenKind::StringLiteral
+                | TokenKind::NumericLiteral
+                | TokenKind::BigIntLiteral
+                | TokenKind::True
+                | TokenKind::False,
+            ) => {
+                Ok(TypeNode::TSLiteralType {
+                    literal: self.parse_literal(),
+                    span: self.last_token,
+                })
+            }
+            Some(TokenKind::Minus)
+                if matches!(
+                    self.peek_next_kind(),
+                    Some(TokenKind::NumericLiteral | TokenKind::BigIntLiteral)
+                ) =>
+            {
+                self.advance();
+                Ok(TypeNode::TSLiteralType {
+                    literal: Expression::UnaryExpression {
+                        operator: "-".to_string(),
+                        argument: Box::new(self.parse_literal()),
+                        prefix: true,
+                        span: self.finish_span(start),
+                    },
+                    span: self.finish_span(start),
+                })
+            }
+            Some(TokenKind::NoSubstitutionTemplate | TokenKind::TemplateHead) => {
+                self.advance();
+                let (quasis, types) = self.parse_template_parts(false, |parser| parser.parse_type_outside_extends())?;
+                Ok(TypeNode::TSTemplateLiteralType {
+                    quasis,
+                    types,
+                    span: self.finish_span(start),
+                })
+            }
+            Some(TokenKind::Typeof) => {
+                self.advance();
+                let expr_name = if self.check(TokenKind::Import) {
+                    TypeQueryName::Import(Box::new(self.parse_import_type()?))
+                } else {
+                    TypeQueryName::Name(self.parse_entity_name()?)
+                };
+                Ok(TypeNode::TSTypeQuery {
+                    expr_name,
+                    span: self.finish_span(start),
+                })
+            }
+            Some(TokenKind::Import) => self.parse_import_type(),
+            Some(TokenKind::LBrace) => {
+                if self.is_start_of_mapped_type() {
+                    self.parse_mapped_type()
+                } else {
+                    let members = self.parse_type_members()?;
+                    Ok(TypeNode::TSTypeLiteral {
+                        members,
+                        span: self.finish_span(start),
+                    })
+                }
+            }
+            Some(TokenKind::LBracket) => self.parse_tuple_type(),
+            Some(TokenKind::LParen) => {
+                self.advance();
+                let inner = self.parse_type_outside_extends()?;
+                self.consume(TokenKind::RParen)?;
+                Ok(inner)
+            }
+            _ => {
+                let type_name = self.parse_entity_name()?;
+                let type_arguments = if self.check(TokenKind::Lt) {
+                    Some(self.parse_type_arguments()?)
+                } else {
+                    None
+                };
+                Ok(TypeNode::TSTypeReference {
+                    type_name,
+                    type_arguments,
+                    span: self.finish_span(start),
+                })
+            }
+        }
+    }
+
+    /// `import("m")`, seguido de um nome qualificado e argumentos de tipo opcionais.
+    fn parse_import_type(&mut self) -> Result<TypeNode, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::Import)?;
+        self.consume(TokenKind::LParen)?;
+        if !self.check(TokenKind::StringLiteral) {
+            return Err(self.expected_token(TokenKind::StringLiteral));
+        }
+        let argument = Box::new(self.parse_literal());
+        self.consume(TokenKind::RParen)?;
+        let qualifier = if self.match_token(TokenKind::Dot) {
+            Some(Box::new(self.parse_entity_name()?))
+        } else {
+            None
+        };
+        let type_arguments = if self.check(TokenKind::Lt) {
+            Some(self.parse_type_arguments()?)
+        } else {
+            None
+        };
+        Ok(TypeNode::TSImportType {
+            argument,
+            qualifier,
+            type_arguments,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// `<A, B>` depois de uma referência de tipo.
+    pub(super) fn parse_type_arguments(&mut self) -> Result<Vec<TypeNode>, ParseError> {
+        self.consume(TokenKind::Lt)?;
+        let mut arguments = Vec::new();
+        loop {
+            arguments.push(self.parse_type_outside_extends()?);
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+        }
+        if !self.match_closing_angle() {
+            return Err(self.expected_token(TokenKind::Gt));
+        }
+        Ok(arguments)
+    }
+
+    /// `<T, ...>` opcional depois do nome de uma declaração ou antes dos parâmetros.
+    pub(super) fn parse_optional_type_parameters(&mut self) -> Result<Option<Vec<TypeParameter>>, ParseError> {
+        if self.check(TokenKind::Lt) {
+            Ok(Some(self.parse_type_parameters()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// `<T, U extends C = D>` depois do nome de uma declaração.
+    pub(super) fn parse_type_parameters(&mut self) -> Result<Vec<TypeParameter>, ParseError> {
+        self.consume(TokenKind::Lt)?;
+        let mut parameters = Vec::new();
+        while !self.check(TokenKind::Gt) {
+            let start = self.start_span();
+            let name = self.parse_identifier()?;
+            let constraint = if self.match_token(TokenKind::Extends) {
+                Some(self.parse_type_outside_extends()?)
+            } else {
+                None
+            };
+            let default = if self.match_token(TokenKind::Eq) {
+                Some(self.parse_type_outside_extends()?)
+            } else {
+                None
+            };
+            parameters.push(TypeParameter {
+                name,
+                constraint,
+                default,
+                span: self.finish_span(start),
+            });
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+        }
+        if parameters.is_empty() {
+            return Err(ParseError::new(
+                ErrorCode::EmptyTypeParameterList,
+                self.current_span(),
+                "Lista de parâmetros de tipo não pode ser vazia",
+            ));
+        }
+        if !self.match_closing_angle() {
+            return Err(self.expected_token(TokenKind::Gt));
+        }
+        Ok(parameters)
+    }
+
+    fn parse_tuple_type(&mut self) -> Result<TypeNode, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::LBracket)?;
+        let mut element_types = Vec::new();
+
+        while !self.check(TokenKind::RBracket) {
+            let element_start = self.start_span();
+            let rest = self.match_token(TokenKind::Ellipsis);
+
+            // `nome: T` ou `nome?: T`
+            let is_named = self.peek_kind().is_some_and(|k| k == TokenKind::Identifier || k.is_keyword())
+                && (self.peek_next_kind() == Some(TokenKind::Colon)
+                    || (self.peek_next_kind() == Some(TokenKind::Question)
+                        && self.tokens.get(self.current + 2).map(|t| t.kind) == Some(TokenKind::Colon)));
+            let member_start = self.start_span();
+            let mut element = if is_named {
+                let label = self.parse_property_identifier()?;
+                let optional = self.match_token(TokenKind::Question);
+                self.consume(TokenKind::Colon)?;
+                let element_type = self.parse_type_outside_extends()?;
+                TypeNode::TSNamedTupleMember {
+                    label,
+                    optional,
+                    element_type: Box::new(element_type),
+                    span: self.finish_span(member_start),
+                }
+            } else {
+                let element_type = self.parse_type_outside_extends()?;
+                if self.match_token(TokenKind::Question) {
+                    TypeNode::TSOptionalType {
+                        type_annotation: Box::new(element_type),
+                        span: self.finish_span(member_start),
+                    }
+                } else {
+                    element_type
+                }
+            };
+
+            if rest {
+                element = TypeNode::TSRestType {
+                    type_annotation: Box::new(element),
+                    span: self.finish_span(element_start),
+                };
+            }
+            element_types.push(element);
+
+            if !self.match_token(TokenKind::Comma) {
+                break;
+            }
+        }
+
+        self.consume(TokenKind::RBracket)?;
+        Ok(TypeNode::TSTupleType {
+            element_types,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// Distingue `(a: T) => R` de um tipo entre parênteses procurando o `=>`
+    /// depois do `)` correspondente.
+    fn is_start_of_function_type(&self) -> bool {
+        match self.peek_kind() {
+            // `<T>(x: T) => T`: em posição de tipo, `<` só pode abrir parâmetros de tipo
+            Some(TokenKind::New | TokenKind::Lt) => true,
+            Some(TokenKind::Abstract) => self.peek_next_kind() == Some(TokenKind::New),
+            Some(TokenKind::LParen) => {
+                let mut depth = 0;
+                for (offset, token) in self.tokens[self.current..].iter().enumerate() {
+                    match token.kind {
+                        TokenKind::LParen => depth += 1,
+                        TokenKind::RParen => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return self.tokens.get(self.current + offset + 1).map(|t| t.kind)
+                                    == Some(TokenKind::Arrow);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_function_type(&mut self) -> Result<TypeNode, ParseError> {
+        let start = self.start_span();
+        let is_abstract = self.match_token(TokenKind::Abstract);
+        let is_constructor = self.match_token(TokenKind::New);
+
+        let type_parameters = self.parse_optional_type_parameters()?;
+        let params = self.parse_params(false, false)?;
+        self.consume(TokenKind::Arrow)?;
+        let return_type = Box::new(self.parse_return_type()?);
+
+        Ok(if is_constructor {
+            TypeNode::TSConstructorType {
+                r#abstract: is_abstract,
+                type_parameters,
+                params,
+                return_type,
+                span: self.finish_span(start),
+            }
+        } else {
+            TypeNode::TSFunctionType {
+                type_parameters,
+                params,
+                return_type,
+                span: self.finish_span(start),
+            }
+        })
+    }
+
+    /// `{ [K in ...` com `readonly`, `+readonly` ou `-readonly` opcional antes.
+    fn is_start_of_mapped_type(&self) -> bool {
+        let kind_at = |offset: usize| self.tokens.get(self.current + offset).map(|t| t.kind);
+        let mut offset = 1;
+        if matches!(kind_at(offset), Some(TokenKind::Plus | TokenKind::Minus)) {
+            offset += 1;
+        }
+        if kind_at(offset) == Some(TokenKind::Readonly) {
+            offset += 1;
+        }
+        kind_at(offset) == Some(TokenKind::LBracket)
+            && kind_at(offset + 1).is_some_and(|k| k == TokenKind::Identifier || k.is_keyword())
+            && kind_at(offset + 2) == Some(TokenKind::In)
+    }
+
+    fn parse_mapped_type(&mut self) -> Result<TypeNode, ParseError> {
+        let start = self.start_span();
+        self.consume(TokenKind::LBrace)?;
+
+        let readonly = self.parse_mapped_modifier(TokenKind::Readonly)?;
+        self.consume(TokenKind::LBracket)?;
+        let key = self.parse_property_identifier()?;
+        self.consume(TokenKind::In)?;
+        let constraint = Box::new(self.parse_type_outside_extends()?);
+        let name_type = if self.match_token(TokenKind::As) {
+            Some(Box::new(self.parse_type_outside_extends()?))
+        } else {
+            None
+        };
+        self.consume(TokenKind::RBracket)?;
+
+        let optional = self.parse_mapped_modifier(TokenKind::Question)?;
+        let type_annotation = if self.match_token(TokenKind::Colon) {
+            Some(Box::new(self.parse_type_outside_extends()?))
+        } else {
+            None
+        };
+        self.match_token(TokenKind::Semicolon);
+        self.consume(TokenKind::RBrace)?;
+
+        Ok(TypeNode::TSMappedType {
+            readonly,
+            key,
+            constraint,
+            name_type,
+            optional,
+            type_annotation,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// `readonly`/`?` de um tipo mapeado, com `+` ou `-` opcional na frente.
+    fn parse_mapped_modifier(&mut self, modifier: TokenKind) -> Result<Option<String>, ParseError> {
+        if matches!(self.peek_kind(), Some(TokenKind::Plus | TokenKind::Minus)) {
+            let sign = self.advance().value.clone();
+            self.consume(modifier)?;
+            Ok(Some(sign))
+        } else if self.match_token(modifier) {
+            Ok(Some("true".to_string()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Lê `{ membro; membro, ... }`, usado por tipos objeto e interfaces.
+    pub(super) fn parse_type_members(&mut self) -> Result<Vec<TypeMember>, ParseError> {
+        self.consume(TokenKind::LBrace)?;
+        let mut members = Vec::new();
+
+        while !self.check(TokenKind::RBrace) && !self.is_at_end() {
+            members.push(self.parse_type_member()?);
+            // Membros são separados por `;`, `,` ou uma quebra de linha; o último pode ficar sem
+            if !self.match_token(TokenKind::Semicolon)
+                && !self.match_token(TokenKind::Comma)
+                && !self.has_newline_before()
+            {
+                break;
+            }
+        }
+
+        self.consume(TokenKind::RBrace)?;
+        Ok(members)
+    }
+
+    fn parse_type_member(&mut self) -> Result<TypeMember, ParseError> {
+        let start = self.start_span();
+        if self.check(TokenKind::LParen) || self.check(TokenKind::Lt) {
+            let type_parameters = self.parse_optional_type_parameters()?;
+            let params = self.parse_params(false, false)?;
+            let return_type = self.parse_optional_return_type()?;
+            return Ok(TypeMember::TSCallSignatureDeclaration {
+                type_parameters,
+                params,
+                return_type,
+                span: self.finish_span(start),
+            });
+        }
+        if self.check(TokenKind::New) && matches!(self.peek_next_kind(), Some(TokenKind::LParen | TokenKind::Lt)) {
+            self.advance();
+            let type_parameters = self.parse_optional_type_parameters()?;
+            let params = self.parse_params(false, false)?;
+            let return_type = self.parse_optional_return_type()?;
+            return Ok(TypeMember::TSConstructSignatureDeclaration {
+                type_parameters,
+                params,
+                return_type,
+                span: self.finish_span(start),
+            });
+        }
+
+        let readonly = self.check(TokenKind::Readonly) && self.is_modifier_position() && {
+            self.advance();
+            true
+        };
+
+        if self.check(TokenKind::LBracket)
+            && self.peek_next_kind().is_some_and(|k| k == TokenKind::Identifier || k.is_keyword())
+            && self.tokens.get(self.current + 2).map(|t| t.kind) == Some(TokenKind::Colon)
+        {
+            let (parameters, type_annotation) = self.parse_index_signature()?;
+            return Ok(TypeMember::TSIndexSignature {
+                parameters,
+                type_annotation: Some(type_annotation),
+                readonly,
+                span: self.finish_span(start),
+            });
+        }
+
+        let mut kind = "method";
+        if matches!(self.peek_kind(), Some(TokenKind::Get | TokenKind::Set)) && self.is_modifier_position() {
+            kind = if self.advance().kind == TokenKind::Get { "get" } else { "set" };
+        }
+
+        let (key, computed) = self.parse_property_name()?;
+        let optional = self.match_token(TokenKind::Question);
+
+        if self.check(TokenKind::LParen) || self.check(TokenKind::Lt) {
+            let type_parameters = self.parse_optional_type_parameters()?;
+            let params = self.parse_params(false, false)?;
+            let return_type = self.parse_optional_return_type()?;
+            return Ok(TypeMember::TSMethodSignature {
+                key,
+                computed,
+                optional,
+                kind: kind.to_string(),
+                type_parameters,
+                params,
+                return_type,
+                span: self.finish_span(start),
+            });
+        }
+
+        let type_annotation = if self.match_token(TokenKind::Colon) {
+            Some(self.parse_type_annotation()?)
+        } else {
+            None
+        };
+        Ok(TypeMember::TSPropertySignature {
+            key,
+            computed,
+            optional,
+            readonly,
+            type_annotation,
+            span: self.finish_span(start),
+        })
+    }
+
+    /// `[chave: T]: R`, a partir do `[`.
+    pub(super) fn parse_index_signature(&mut self) -> Result<(Vec<Param>, TypeNode), ParseError> {
+        self.consume(TokenKind::LBracket)?;
+        let start = self.start_span();
+        let id = self.parse_property_identifier()?;
+        self.consume(TokenKind::Colon)?;
+        let type_annotation = Some(self.parse_type_annotation()?);
+        let parameter = Param {
+            decorators: Vec::new(),
+            id: Pattern::Identifier(id),
+            optional: false,
+            type_annotation,
+            parameter_property: None,
+            span: self.finish_span(start),
+        };
+        self.consume(TokenKind::RBracket)?;
+        self.consume(TokenKind::Colon)?;
+        let type_annotation = self.parse_type_annotation()?;
+        Ok((vec![parameter], type_annotation))
+    }
+
+    pub(super) fn parse_optional_return_type(&mut self) -> Result<Option<TypeNode>, ParseError> {
+        if self.match_token(TokenKind::Colon) {
+            Ok(Some(self.parse_return_type()?))
+        } else {
+            Ok(None)
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Testes de regressão do analisador léxico.
+
+use rustots::lexer::{Lexer, Token, TokenKind};
+
+/// Tokens de `source`, sem espaços, quebras de linha e comentários.
+fn tokens(source: &str) -> Vec<Token> {
+    Lexer::new(source).tokenize().into_iter().filter(|t| !t.kind.is_trivia()).collect()
+}
+
+fn regex_count(source: &str) -> usize {
+    Lexer::new(source)
+        .tokenize()
+        .iter()
+        .filter(|t| t.kind == TokenKind::RegExpLiteral)
+        .count()
+}
+
+#[test]
+fn slash_after_property_name_is_division() {
+    for source in [
+        "obj.return / 2 / 1;",
+        "a.of / 2 / 1;",
+        "x.delete / n / 2;",
+        "a?.new / 2 / 1;",
+        "let of = 4; x = of / 2 / 1;",
+        "x = f(a) / 2 / 1;",
+    ] {
+        assert_eq!(regex_count(source), 0, "{source}");
+    }
+}
+
+#[test]
+fn slash_after_statement_head_starts_regex() {
+    for source in [
+        "if (x) /re/.test(y);",
+        "while (f(x)) /re/.exec(y);",
+        "x = await /re/;",
+        "for (const a of /x/g.exec(s)) {}",
+        "for await (const a of /x/.y) {}",
+    ] {
+        assert_eq!(regex_count(source), 1, "{source}");
+    }
+}
+
+#[test]
+fn numeric_literals_are_single_tokens() {
+    for source in ["0xFF", "0b1010", "0o755", "1_000_000", "1e-9", "2.5E+3", ".5", "5.", "0"] {
+        let tokens = tokens(source);
+        assert_eq!(tokens.len(), 1, "{source}");
+        assert_eq!(tokens[0].kind, TokenKind::NumericLiteral, "{source}");
+        assert!(tokens[0].malformed.is_none(), "{source}");
+    }
+    for source in ["10n", "0xFFn", "0b1n"] {
+        let tokens = tokens(source);
+        assert_eq!(tokens.len(), 1, "{source}");
+        assert_eq!(tokens[0].kind, TokenKind::BigIntLiteral, "{source}");
+        assert!(tokens[0].malformed.is_none(), "{source}");
+    }
+}
+
+#[test]
+fn malformed_numbers_are_flagged() {
+    for source in ["0x", "1__0", "08n", "1e", "1e+", "1_", "0b2", "1.5n"] {
+        let tokens = tokens(source);
+        assert_eq!(tokens.len(), 1, "{source}");
+        assert!(tokens[0].malformed.is_some(), "{source}");
+    }
+}
+
+#[test]
+fn templates_are_split_around_substitutions() {
+    let kinds: Vec<_> = tokens("`a${b + `c${d}`}e${ {f}.f }\ng`").iter().map(|t| t.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::TemplateHead,
+            TokenKind::Identifier,
+            TokenKind::Plus,
+            TokenKind::TemplateHead,
+            TokenKind::Identifier,
+            TokenKind::TemplateTail,
+            TokenKind::TemplateMiddle,
+            TokenKind::LBrace,
+            TokenKind::Identifier,
+            TokenKind::RBrace,
+            TokenKind::Dot,
+            TokenKind::Identifier,
+            TokenKind::TemplateTail,
+        ]
+    );
+    let tokens = tokens("`linha 1\nlinha 2`");
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].kind, TokenKind::NoSubstitutionTemplate);
+}
+
+#[test]
+fn punctuators_use_maximal_munch() {
+    for (punctuator, kind) in [
+        ("?.", TokenKind::QuestionDot),
+        ("??", TokenKind::QuestionQuestion),
+        ("??=", TokenKind::QuestionQuestionEq),
+        ("**=", TokenKind::StarStarEq),
+        (">>>", TokenKind::UnsignedShiftRight),
+        (">>>=", TokenKind::UnsignedShiftRightEq),
+        ("&&=", TokenKind::AmpAmpEq),
+        ("||=", TokenKind::PipePipeEq),
+        ("&=", TokenKind::AmpEq),
+        ("|=", TokenKind::PipeEq),
+        ("^=", TokenKind::CaretEq),
+        ("%=", TokenKind::PercentEq),
+        ("/=", TokenKind::SlashEq),
+        ("<<=", TokenKind::ShiftLeftEq),
+        ("...", TokenKind::Ellipsis),
+    ] {
+        let tokens = tokens(&format!("a {punctuator} b"));
+        assert_eq!(tokens.len(), 3, "{punctuator}");
+        assert_eq!(tokens[1].kind, kind, "{punctuator}");
+    }
+    // `?.` seguido de dígito é o `?` de uma condicional
+    let kinds: Vec<_> = tokens("a?.5:b").iter().map(|t| t.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::Identifier,
+            TokenKind::Question,
+            TokenKind::NumericLiteral,
+            TokenKind::Colon,
+            TokenKind::Identifier,
+        ]
+    );
+}
+
+#[test]
+fn tokens_serialize_category_and_kind() {
+    let json: Vec<(String, String)> = tokens("let s = \"a\" + 1 + 2n + /r/g + `t`;")
+        .iter()
+        .map(|token| {
+            let value = serde_json::to_value(token).unwrap();
+            (value["type"].as_str().unwrap().to_string(), value["kind"].as_str().unwrap().to_string())
+        })
+        .filter(|(_, kind)| kind != "Plus")
+        .collect();
+    let expected = [
+        ("keyword", "Let"),
+        ("identifier", "Identifier"),
+        ("operator", "Eq"),
+        ("literal", "StringLiteral"),
+        ("literal", "NumericLiteral"),
+        ("literal", "BigIntLiteral"),
+        ("regexp", "RegExpLiteral"),
+        ("template", "NoSubstitutionTemplate"),
+        ("punctuation", "Semicolon"),
+    ];
+    assert_eq!(json, expected.map(|(category, kind)| (category.to_string(), kind.to_string())));
+}
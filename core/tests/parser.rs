@@ -0,0 +1,669 @@
+//! Testes de regressão do analisador, sobre o JSON da AST.
+
+use rustots::lexer::Lexer;
+use rustots::parser::{ErrorCode, ParseError, Parser};
+use serde_json::Value;
+
+fn parse(source: &str) -> (Value, Vec<ParseError>) {
+    let tokens = Lexer::new(source).tokenize();
+    let (ast, errors) = Parser::new(tokens).parse();
+    (serde_json::to_value(ast).unwrap(), errors)
+}
+
+/// Primeiro nó, em pré-ordem, cujo `type` é `kind`.
+fn find<'a>(node: &'a Value, kind: &str) -> Option<&'a Value> {
+    match node {
+        Value::Object(map) if map.get("type").and_then(Value::as_str) == Some(kind) => Some(node),
+        Value::Object(map) => map.values().find_map(|value| find(value, kind)),
+        Value::Array(items) => items.iter().find_map(|value| find(value, kind)),
+        _ => None,
+    }
+}
+
+#[test]
+fn unterminated_strings_do_not_panic() {
+    for source in ["x = \"", "import a from \"", "x = \"é", "x = '", "x = \"a\\\""] {
+        let (ast, _) = parse(source);
+        assert!(find(&ast, "Literal").is_some(), "{source}");
+    }
+    let (ast, _) = parse("x = \"abc");
+    assert_eq!(find(&ast, "Literal").unwrap()["value"], "abc");
+    let (ast, _) = parse("x = \"a\\\"");
+    assert_eq!(find(&ast, "Literal").unwrap()["value"], "a\"");
+    let (ast, _) = parse("x = \"é\"");
+    assert_eq!(find(&ast, "Literal").unwrap()["value"], "é");
+}
+
+fn codes(errors: &[ParseError]) -> Vec<ErrorCode> {
+    errors.iter().map(|e| e.code).collect()
+}
+
+#[test]
+fn nested_literals_are_parsed_once() {
+    // Antes, cada `[`/`{` era lido duas vezes, e 20 níveis levavam segundos
+    for (open, close) in [("f([", "])"), ("([", "])"), ("({a:", "})")] {
+        let source = format!("x = {}1{};", open.repeat(20), close.repeat(20));
+        let (_, errors) = parse(&source);
+        assert!(errors.is_empty(), "{source}");
+    }
+}
+
+#[test]
+fn assignment_literals_become_patterns() {
+    let (ast, errors) = parse("({ a, b: [c], d = 1, ...e } = f); [x = 1, [y] = [2], ...z] = w;");
+    assert!(errors.is_empty());
+    assert!(find(&ast, "ObjectPattern").is_some());
+    assert!(find(&ast, "ArrayPattern").is_some());
+    assert!(find(&ast, "ObjectExpression").is_none());
+
+    for source in ["({ a = 1 });", "f({ a = 1 });", "[{ a = 1 } + 2] = x;"] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::ExpectedToken], "{source}");
+    }
+    for source in ["[...a, b] = c;", "[...a,] = c;", "({ ...{ a } } = x);"] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::InvalidRestElement], "{source}");
+    }
+    for source in ["([a]) = 1;", "[a + b] = x;", "({ a() {} } = x);"] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::InvalidAssignmentTarget], "{source}");
+    }
+}
+
+#[test]
+fn for_in_of_heads_become_patterns() {
+    let (ast, errors) = parse("for ([a, b] of xs) {} for ({ a, b = 1 } of xs) {}");
+    assert!(errors.is_empty());
+    assert!(find(&ast, "ArrayPattern").is_some());
+    assert!(find(&ast, "ObjectPattern").is_some());
+
+    for source in ["for (a + b of xs) {}", "for (f() in xs) {}", "for (([a]) of xs) {}"] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::InvalidAssignmentTarget], "{source}");
+    }
+    assert_eq!(codes(&parse("for ({ a = 1 }; ;) {}").1)[0], ErrorCode::ExpectedToken);
+}
+
+#[test]
+fn for_in_of_declarations_reject_initializers() {
+    for source in ["for (const x = 1 of xs) {}", "for (let [a] = [] in o) {}"] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::InvalidForHead], "{source}");
+    }
+}
+
+#[test]
+fn declarations_are_not_single_statement_bodies() {
+    for source in [
+        "while (1) let x = 1;",
+        "if (a) class A {}",
+        "if (a) b; else function f() {}",
+        "a: const x = 1;",
+        "for (;;) type T = 1;",
+    ] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::DeclarationNotAllowed], "{source}");
+    }
+    assert!(parse("while (1) var x = 1; a: for (;;) {}").1.is_empty());
+}
+
+#[test]
+fn abstract_and_type_before_newline_are_identifiers() {
+    for source in ["type\nFoo = 1;", "abstract\nclass A {}"] {
+        let (ast, errors) = parse(source);
+        assert!(errors.is_empty(), "{source}");
+        assert_eq!(ast["body"][0]["type"], "ExpressionStatement", "{source}");
+    }
+}
+
+#[test]
+fn type_modifier_before_as_in_specifiers() {
+    let specifier = |source: &str| {
+        let (ast, errors) = parse(source);
+        assert!(errors.is_empty(), "{source}");
+        let specifier = &ast["body"][0]["specifiers"][0];
+        let kind = specifier.get("import_kind").or(specifier.get("export_kind")).unwrap().clone();
+        (specifier["local"]["name"].clone(), kind)
+    };
+    assert_eq!(specifier("import { type as } from \"x\";"), ("as".into(), "type".into()));
+    assert_eq!(specifier("import { type as as x } from \"x\";"), ("x".into(), "type".into()));
+    assert_eq!(specifier("import { type as as } from \"x\";"), ("as".into(), "value".into()));
+    assert_eq!(specifier("import { type as x } from \"x\";"), ("x".into(), "value".into()));
+    assert_eq!(specifier("export { type as };"), ("as".into(), "type".into()));
+}
+
+#[test]
+fn const_assertions_have_their_own_node() {
+    for source in ["x = y as const;", "x = <const>[\"a\"];"] {
+        let (ast, errors) = parse(source);
+        assert!(errors.is_empty(), "{source}");
+        assert!(find(&ast, "TSConstType").is_some(), "{source}");
+        assert!(find(&ast, "TSTypeReference").is_none(), "{source}");
+    }
+}
+
+#[test]
+fn parameter_decorators_only_in_class_methods() {
+    assert!(parse("class A { constructor(@d x) {} m(@d y) {} }").1.is_empty());
+    for source in [
+        "function f(@d x) {}",
+        "x = function (@d a) {};",
+        "x = (@d a) => a;",
+        "x = { m(@d a) {} };",
+        "class A { set v(@d x) {} }",
+    ] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::InvalidDecorator], "{source}");
+    }
+}
+
+#[test]
+fn non_finite_numbers_are_not_null() {
+    let (ast, errors) = parse("x = 1e400;");
+    assert!(errors.is_empty());
+    assert_eq!(find(&ast, "Literal").unwrap()["value"], serde_json::json!({ "number": "Infinity" }));
+    let (ast, _) = parse("x = null;");
+    assert!(find(&ast, "Literal").unwrap()["value"].is_null());
+}
+
+#[test]
+fn decorators_carry_a_type_tag() {
+    let (ast, errors) = parse("@d class A { m(@p x) {} }");
+    assert!(errors.is_empty());
+    assert_eq!(ast["body"][0]["decorators"][0]["type"], "Decorator");
+}
+
+#[test]
+fn export_specifiers_carry_a_type_tag() {
+    let (ast, errors) = parse("export { a as b, type c };");
+    assert!(errors.is_empty());
+    assert_eq!(ast["body"][0]["specifiers"][0]["type"], "ExportSpecifier");
+    assert_eq!(ast["body"][0]["specifiers"][1]["export_kind"], "type");
+}
+
+#[test]
+fn only_parameter_properties_carry_modifiers() {
+    let (ast, errors) = parse("class A { constructor(private readonly x: T, y) {} } f = (a) => a;");
+    assert!(errors.is_empty());
+    let params = &find(&ast, "MethodDefinition").unwrap()["value"]["params"];
+    assert_eq!(params[0]["parameter_property"]["accessibility"], "private");
+    assert_eq!(params[0]["parameter_property"]["readonly"], true);
+    assert!(params[0].get("static").is_none());
+    assert!(params[1].get("parameter_property").is_none());
+    let arrow = find(&ast, "ArrowFunctionExpression").unwrap();
+    assert!(arrow["params"][0].get("parameter_property").is_none());
+    assert!(arrow["params"][0].get("readonly").is_none());
+}
+
+#[test]
+fn typeof_accepts_import_types() {
+    let (ast, errors) = parse("type A = typeof import(\"x\").a.b; let c: import(\"x\").Foo<string>;");
+    assert!(errors.is_empty());
+    let query = find(&ast, "TSTypeQuery").unwrap();
+    assert_eq!(query["expr_name"]["type"], "TSImportType");
+    assert_eq!(query["expr_name"]["argument"]["value"], "x");
+    assert_eq!(query["expr_name"]["qualifier"]["type"], "MemberExpression");
+    let (ast, _) = parse("type D = typeof a.b;");
+    assert_eq!(find(&ast, "TSTypeQuery").unwrap()["expr_name"]["type"], "MemberExpression");
+}
+
+#[test]
+fn template_elements_have_cooked_values() {
+    let (ast, errors) = parse("x = `a\\n${b}\\x41`;");
+    assert!(errors.is_empty());
+    let quasis = &find(&ast, "TemplateLiteral").unwrap()["quasis"];
+    assert_eq!(quasis[0]["raw"], "a\\n");
+    assert_eq!(quasis[0]["cooked"], "a\n");
+    assert_eq!(quasis[1]["cooked"], "A");
+
+    for source in ["x = `a\\u{zz}`;", "x = `\\01`;"] {
+        let (ast, errors) = parse(source);
+        assert_eq!(codes(&errors), [ErrorCode::InvalidEscapeSequence], "{source}");
+        assert!(find(&ast, "TemplateLiteral").unwrap()["quasis"][0]["cooked"].is_null());
+    }
+    let (ast, errors) = parse("x = tag`\\u{zz}`;");
+    assert!(errors.is_empty());
+    assert!(find(&ast, "TaggedTemplateExpression").unwrap()["quasi"]["quasis"][0]["cooked"].is_null());
+}
+
+#[test]
+fn const_and_destructuring_declarations_need_initializers() {
+    for source in ["const a;", "const { a };", "let [b];", "export const q;", "for (const i; ;) {}"] {
+        assert_eq!(codes(&parse(source).1)[0], ErrorCode::MissingInitializer, "{source}");
+    }
+    for source in [
+        "let x, y; var z;",
+        "for (const x of xs) {} for (const { a } in o) {}",
+        "declare const d: number;",
+        "export declare let [e]: T[];",
+    ] {
+        assert!(parse(source).1.is_empty(), "{source}");
+    }
+}
+
+#[test]
+fn class_members_recover_one_at_a_time() {
+    let (ast, errors) = parse("class A {\n  x = ;\n  m() {}\n  y: = 1\n  z = 2;\n}\nlet after;");
+    assert_eq!(errors.len(), 2);
+    let names: Vec<_> = find(&ast, "ClassDeclaration").unwrap()["body"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|member| member["key"]["name"].clone())
+        .collect();
+    assert_eq!(names, ["m", "z"]);
+    assert!(find(&ast, "VariableDeclaration").is_some());
+}
+
+#[test]
+fn semantic_errors_keep_the_statement() {
+    for (source, code, kind) in [
+        ("while (a) { break foo; }", ErrorCode::UndefinedLabel, "BreakStatement"),
+        ("a: { while (b) continue a; }", ErrorCode::InvalidJump, "ContinueStatement"),
+        ("continue;", ErrorCode::InvalidJump, "ContinueStatement"),
+        ("a: a: x;", ErrorCode::DuplicateLabel, "LabeledStatement"),
+        ("switch (a) { default: x; default: y; }", ErrorCode::DuplicateDefaultClause, "SwitchStatement"),
+    ] {
+        let (ast, errors) = parse(source);
+        assert_eq!(codes(&errors), [code], "{source}");
+        assert!(find(&ast, kind).is_some(), "{source}");
+        assert!(find(&ast, "Error").is_none(), "{source}");
+    }
+}
+
+#[test]
+fn deep_nesting_is_an_error_not_a_stack_overflow() {
+    // Sem otimizações cada nível usa dezenas de KB de pilha, mais que os 2 MB
+    // de uma thread de teste
+    let run = || {
+        for open in ["(", "[", "{a:", "f(", "!", "-(", "a ? b : "] {
+            let source = format!("x = {}1;", open.repeat(3000));
+            let (_, errors) = parse(&source);
+            assert_eq!(codes(&errors)[0], ErrorCode::NestingTooDeep, "{open}");
+        }
+        let source = format!("x = {}1{};", "(".repeat(100), ")".repeat(100));
+        assert!(parse(&source).1.is_empty());
+    };
+    std::thread::Builder::new().stack_size(64 << 20).spawn(run).unwrap().join().unwrap();
+}
+
+#[test]
+fn parenthesized_arrow_heads_are_found_by_scanning() {
+    let (ast, errors) = parse("x = a ? (b) : c => d;");
+    assert!(errors.is_empty());
+    let conditional = find(&ast, "ConditionalExpression").unwrap();
+    assert_eq!(conditional["consequent"]["name"], "b");
+    assert_eq!(conditional["alternate"]["type"], "ArrowFunctionExpression");
+
+    let (ast, errors) = parse("x = a ? (b): T => c : d;");
+    assert!(errors.is_empty());
+    assert_eq!(find(&ast, "ConditionalExpression").unwrap()["consequent"]["type"], "ArrowFunctionExpression");
+
+    for source in ["x = <T>(a: T): T => a;", "x = async (a) => a;", "x = ({ a }: T, [b] = c) => a;"] {
+        let (ast, errors) = parse(source);
+        assert!(errors.is_empty(), "{source}");
+        assert!(find(&ast, "ArrowFunctionExpression").is_some(), "{source}");
+    }
+    for source in ["x = <T>y;", "x = async(a);", "x = (a, b);"] {
+        let (ast, errors) = parse(source);
+        assert!(errors.is_empty(), "{source}");
+        assert!(find(&ast, "ArrowFunctionExpression").is_none(), "{source}");
+    }
+}
+
+#[test]
+fn nested_parentheses_are_not_reparsed() {
+    // Cada `(` era lido como parâmetros e de novo como expressão, e 25 níveis
+    // de valores padrão levavam minutos
+    let run = || {
+        for open in ["(a = ", "(a, (b = ", "f((a) => "] {
+            let source = format!("x = {}1;", open.repeat(25));
+            let started = std::time::Instant::now();
+            parse(&source);
+            assert!(started.elapsed().as_secs() < 1, "{open}");
+        }
+    };
+    std::thread::Builder::new().stack_size(64 << 20).spawn(run).unwrap().join().unwrap();
+}
+
+#[test]
+fn templates_become_template_literals() {
+    let (ast, errors) = parse("x = `a${b}c${`d${e}`}`;");
+    assert!(errors.is_empty());
+    let template = find(&ast, "TemplateLiteral").unwrap();
+    assert_eq!(template["quasis"].as_array().unwrap().len(), 3);
+    assert_eq!(template["expressions"].as_array().unwrap().len(), 2);
+    assert_eq!(template["quasis"][2]["tail"], true);
+    assert_eq!(template["expressions"][1]["type"], "TemplateLiteral");
+
+    let (ast, errors) = parse("sql`select ${a}\nfrom t`;");
+    assert!(errors.is_empty());
+    let tagged = find(&ast, "TaggedTemplateExpression").unwrap();
+    assert_eq!(tagged["tag"]["name"], "sql");
+    assert_eq!(tagged["quasi"]["quasis"][1]["raw"], "\nfrom t");
+}
+
+#[test]
+fn shifts_close_nested_type_arguments() {
+    for source in [
+        "let a: Map<string, Array<number>> = m;",
+        "let b: A<B<C<D>>> = x;",
+        "let c: A<B<C>>= x;",
+        "x = a >> b >>> c;",
+    ] {
+        assert!(parse(source).1.is_empty(), "{source}");
+    }
+    let (ast, _) = parse("x = a >>> b;");
+    assert_eq!(find(&ast, "BinaryExpression").unwrap()["operator"], ">>>");
+}
+
+#[test]
+fn type_annotations_build_type_nodes() {
+    for (annotation, kind) in [
+        ("Array<string>", "TSTypeReference"),
+        ("string | null", "TSUnionType"),
+        ("A & B", "TSIntersectionType"),
+        ("number[]", "TSArrayType"),
+        ("[a: string, b?: number, ...rest: T[]]", "TSTupleType"),
+        ("(a: number) => void", "TSFunctionType"),
+        ("abstract new () => T", "TSConstructorType"),
+        ("{ a: string; b?(): void; [k: string]: unknown }", "TSTypeLiteral"),
+        ("'a'", "TSLiteralType"),
+        ("keyof T", "TSTypeOperator"),
+        ("typeof a.b", "TSTypeQuery"),
+        ("T['a']", "TSIndexedAccessType"),
+        ("T extends (infer U)[] ? U : never", "TSConditionalType"),
+        ("{ readonly [K in keyof T]?: T[K] }", "TSMappedType"),
+        ("`a${B}`", "TSTemplateLiteralType"),
+    ] {
+        let (ast, errors) = parse(&format!("let x: {annotation};"));
+        assert!(errors.is_empty(), "{annotation}");
+        assert_eq!(ast["body"][0]["declarations"][0]["type_annotation"]["type"], kind, "{annotation}");
+    }
+    assert!(find(&parse("let x: T extends (infer U)[] ? U : never;").0, "TSInferType").is_some());
+
+    let (ast, errors) = parse("function f(a: A<B>, b?: C): D | E { } class K { p: P = p; }");
+    assert!(errors.is_empty());
+    let function = find(&ast, "FunctionDeclaration").unwrap();
+    assert_eq!(function["params"][0]["type_annotation"]["type"], "TSTypeReference");
+    assert_eq!(function["params"][1]["optional"], true);
+    assert_eq!(function["return_type"]["type"], "TSUnionType");
+    assert_eq!(find(&ast, "PropertyDefinition").unwrap()["type_annotation"]["type"], "TSTypeReference");
+}
+
+#[test]
+fn module_declarations_carry_their_specifiers() {
+    let (ast, errors) = parse("import d, { a as b, type c } from \"m\" with { type: \"json\" };");
+    assert!(errors.is_empty());
+    let import = &ast["body"][0];
+    assert_eq!(import["type"], "ImportDeclaration");
+    assert_eq!(import["attributes"][0]["key"]["name"], "type");
+    let specifiers: Vec<_> = import["specifiers"].as_array().unwrap().iter().map(|s| &s["type"]).collect();
+    assert_eq!(specifiers, ["ImportDefaultSpecifier", "ImportSpecifier", "ImportSpecifier"]);
+    assert_eq!(import["specifiers"][1]["imported"]["name"], "a");
+    assert_eq!(import["specifiers"][1]["local"]["name"], "b");
+    assert_eq!(import["specifiers"][2]["import_kind"], "type");
+
+    let (ast, errors) = parse(
+        "import * as ns from \"m\"; import \"side\"; import type T from \"t\";
+         export { a as b } from \"m\"; export * as n from \"m\"; export default function () {}
+         export const c = 1; export = x; import r = require(\"r\");",
+    );
+    assert!(errors.is_empty());
+    let kinds: Vec<_> = ast["body"].as_array().unwrap().iter().map(|s| &s["type"]).collect();
+    assert_eq!(
+        kinds,
+        [
+            "ImportDeclaration",
+            "ImportDeclaration",
+            "ImportDeclaration",
+            "ExportNamedDeclaration",
+            "ExportAllDeclaration",
+            "ExportDefaultDeclaration",
+            "ExportNamedDeclaration",
+            "TSExportAssignment",
+            "TSImportEqualsDeclaration",
+        ]
+    );
+    assert_eq!(ast["body"][0]["specifiers"][0]["type"], "ImportNamespaceSpecifier");
+    assert!(ast["body"][1]["specifiers"].as_array().unwrap().is_empty());
+    assert_eq!(ast["body"][2]["import_kind"], "type");
+    assert_eq!(ast["body"][4]["exported"]["name"], "n");
+    assert_eq!(ast["body"][6]["declaration"]["type"], "VariableDeclaration");
+}
+
+#[test]
+fn functions_in_expressions() {
+    let (ast, errors) = parse(
+        "xs.map(x => x * 2);
+         f(async (a: T): Promise<U> => { await a; });
+         g = function* named() { yield* h(); };
+         async function k() {}",
+    );
+    assert!(errors.is_empty());
+    let body = &ast["body"];
+    let arrow = &body[0]["expression"]["arguments"][0];
+    assert_eq!(arrow["type"], "ArrowFunctionExpression");
+    assert_eq!(arrow["expression"], true);
+    assert_eq!(arrow["body"]["type"], "BinaryExpression");
+
+    let arrow = &body[1]["expression"]["arguments"][0];
+    assert_eq!(arrow["async"], true);
+    assert_eq!(arrow["expression"], false);
+    assert_eq!(arrow["params"][0]["type_annotation"]["type"], "TSTypeReference");
+    assert_eq!(arrow["return_type"]["type_name"]["name"], "Promise");
+    assert!(find(arrow, "AwaitExpression").is_some());
+
+    let function = &body[2]["expression"]["right"];
+    assert_eq!(function["type"], "FunctionExpression");
+    assert_eq!(function["generator"], true);
+    assert_eq!(find(function, "YieldExpression").unwrap()["delegate"], true);
+    assert_eq!(body[3]["type"], "FunctionDeclaration");
+    assert_eq!(body[3]["async"], true);
+
+    // Fora de uma função assíncrona `await` é um nome comum; fora de um gerador, `yield` é um erro
+    assert!(parse("function f() { return await; }").1.is_empty());
+    assert_eq!(codes(&parse("function* g() { function f() { yield 1; } }").1), [ErrorCode::YieldOutsideGenerator]);
+}
+
+/// A expressão de `x = ...` com parênteses em volta de cada operação binária.
+fn grouped(source: &str) -> String {
+    fn show(node: &Value) -> String {
+        match node["type"].as_str() {
+            Some("BinaryExpression" | "LogicalExpression") => {
+                format!("({} {} {})", show(&node["left"]), node["operator"].as_str().unwrap(), show(&node["right"]))
+            }
+            Some("TSAsExpression") => format!("({} as T)", show(&node["expression"])),
+            Some("UnaryExpression") => format!("{}{}", node["operator"].as_str().unwrap(), show(&node["argument"])),
+            _ => node["name"].as_str().unwrap_or("?").to_string(),
+        }
+    }
+    let (ast, errors) = parse(source);
+    assert!(errors.is_empty(), "{source}");
+    show(&ast["body"][0]["expression"]["right"])
+}
+
+#[test]
+fn binary_operators_follow_precedence_and_associativity() {
+    for (source, expected) in [
+        ("x = a + b * c - d;", "((a + (b * c)) - d)"),
+        ("x = a ** b ** c;", "(a ** (b ** c))"),
+        ("x = a || b && c | d ^ e & f;", "(a || (b && (c | (d ^ (e & f)))))"),
+        ("x = a == b < c << d;", "(a == (b < (c << d)))"),
+        ("x = a ?? b ?? c;", "((a ?? b) ?? c)"),
+        ("x = a + b as T;", "((a + b) as T)"),
+        ("x = a < b as T;", "((a < b) as T)"),
+        ("x = a as T === b;", "((a as T) === b)"),
+        ("x = -a * b;", "(-a * b)"),
+        ("x = a in b instanceof c;", "((a in b) instanceof c)"),
+    ] {
+        assert_eq!(grouped(source), expected, "{source}");
+    }
+    assert_eq!(codes(&parse("x = -a ** b;").1), [ErrorCode::UnparenthesizedUnaryExponent]);
+    assert!(parse("x = (-a) ** b;").1.is_empty());
+}
+
+#[test]
+fn nodes_carry_byte_offsets_and_lines() {
+    fn check_spans(node: &Value) {
+        match node {
+            Value::Object(map) => {
+                if map.contains_key("type") {
+                    let span = &map["span"];
+                    assert!(span["start"].as_u64() <= span["end"].as_u64(), "{node}");
+                }
+                map.values().for_each(check_spans);
+            }
+            Value::Array(items) => items.iter().for_each(check_spans),
+            _ => {}
+        }
+    }
+
+    let (ast, errors) = parse("let a = \"é\";\n  foo(b);");
+    assert!(errors.is_empty());
+    check_spans(&ast);
+    // `é` ocupa dois bytes
+    let span = &ast["body"][1]["expression"]["callee"]["span"];
+    assert_eq!((span["start"].as_u64(), span["end"].as_u64()), (Some(16), Some(19)));
+    assert_eq!((span["start_line"].as_u64(), span["start_column"].as_u64()), (Some(2), Some(3)));
+    assert_eq!((span["end_line"].as_u64(), span["end_column"].as_u64()), (Some(2), Some(6)));
+    // O nó termina no último token que consumiu, sem os espaços depois dele
+    let span = &ast["body"][0]["span"];
+    assert_eq!((span["start"].as_u64(), span["end"].as_u64()), (Some(0), Some(13)));
+}
+
+#[test]
+fn errors_carry_code_span_and_expected_tokens() {
+    let (ast, errors) = parse("if (a {}\nlet x = 1");
+    assert_eq!(codes(&errors), [ErrorCode::ExpectedToken]);
+    assert_eq!(errors[0].to_string(), "1:7 RTS1001: ')' esperado, encontrado '{'");
+
+    let error = serde_json::to_value(&errors[0]).unwrap();
+    assert_eq!(error["code"], "RTS1001");
+    assert_eq!(error["message"], "')' esperado, encontrado '{'");
+    assert_eq!(error["span"]["start"], 6);
+    let expected: Vec<_> = error["expected"].as_array().unwrap().iter().filter_map(Value::as_str).collect();
+    for token in [")", ",", "(", "?."] {
+        assert!(expected.contains(&token), "{token}");
+    }
+    assert!(find(&ast, "VariableDeclaration").is_some());
+
+    // Erros que não são de token inesperado não listam tokens
+    let (_, errors) = parse("continue;");
+    assert!(errors[0].expected.is_empty());
+}
+
+#[test]
+fn semicolons_are_inserted_at_line_breaks() {
+    let (ast, errors) = parse("let a = 1\nconst b = a\nfoo(b)\nfunction f() { return }\n");
+    assert!(errors.is_empty());
+    assert_eq!(ast["body"].as_array().unwrap().len(), 4);
+
+    // Produções restritas: a quebra de linha encerra a instrução
+    let (ast, errors) = parse("function f() { return\nx }");
+    assert!(errors.is_empty());
+    assert!(find(&ast, "ReturnStatement").unwrap()["argument"].is_null());
+    let (ast, errors) = parse("a\n++b");
+    assert!(errors.is_empty());
+    assert_eq!(ast["body"][1]["expression"]["type"], "UpdateExpression");
+    assert_eq!(ast["body"][1]["expression"]["prefix"], true);
+    let (ast, errors) = parse("l: while (x) { continue\nl }");
+    assert!(errors.is_empty());
+    assert!(find(&ast, "ContinueStatement").unwrap()["label"].is_null());
+    assert_eq!(codes(&parse("throw\nnew Error()").1)[0], ErrorCode::LineBreakNotAllowed);
+
+    // Sem quebra de linha, ou quando a linha seguinte continua a expressão, não há inserção
+    let (ast, errors) = parse("a\n(b)");
+    assert!(errors.is_empty());
+    assert_eq!(ast["body"][0]["expression"]["type"], "CallExpression");
+    assert_eq!(codes(&parse("let a = 1 let b = 2").1), [ErrorCode::ExpectedToken]);
+}
+
+#[test]
+fn generics_on_declarations_and_calls() {
+    let (ast, errors) = parse(
+        "function f<T extends X = Y>(a: T) {}
+         class Box<T> { m<U>() {} }
+         interface I<T> {}
+         type A<T> = Map<T, T>;
+         foo<string>(x);
+         new Map<K, V>();",
+    );
+    assert!(errors.is_empty());
+    let body = &ast["body"];
+    let parameter = &body[0]["type_parameters"][0];
+    assert_eq!(parameter["name"]["name"], "T");
+    assert_eq!(parameter["constraint"]["type_name"]["name"], "X");
+    assert_eq!(parameter["default"]["type_name"]["name"], "Y");
+    assert_eq!(body[1]["type_parameters"][0]["name"]["name"], "T");
+    assert_eq!(find(&body[1], "MethodDefinition").unwrap()["value"]["type_parameters"][0]["name"]["name"], "U");
+    assert_eq!(body[2]["type_parameters"][0]["name"]["name"], "T");
+    assert_eq!(body[3]["type_parameters"][0]["name"]["name"], "T");
+    assert_eq!(body[3]["type_annotation"]["type_arguments"].as_array().unwrap().len(), 2);
+    assert_eq!(body[4]["expression"]["type_arguments"][0]["keyword"], "string");
+    assert_eq!(body[5]["expression"]["type_arguments"].as_array().unwrap().len(), 2);
+
+    // Sem `(` depois do `>`, `<` e `>` são comparações
+    assert_eq!(grouped("x = a < b > c;"), "((a < b) > c)");
+    assert_eq!(grouped("x = a < b;"), "(a < b)");
+    assert_eq!(codes(&parse("function f<>() {}").1), [ErrorCode::EmptyTypeParameterList]);
+}
+
+#[test]
+fn optional_chains_and_nullish_coalescing() {
+    let (ast, errors) = parse("a?.b.c; (a?.b).c; a?.[x]?.(y);");
+    assert!(errors.is_empty());
+    let body = &ast["body"];
+    // O `ChainExpression` envolve a cadeia inteira, até onde o curto-circuito chega
+    let chain = &body[0]["expression"];
+    assert_eq!(chain["type"], "ChainExpression");
+    assert_eq!(chain["expression"]["optional"], false);
+    assert_eq!(chain["expression"]["object"]["optional"], true);
+    let member = &body[1]["expression"];
+    assert_eq!(member["type"], "MemberExpression");
+    assert_eq!(member["object"]["type"], "ChainExpression");
+    let call = &body[2]["expression"]["expression"];
+    assert_eq!(call["type"], "CallExpression");
+    assert_eq!(call["optional"], true);
+    assert_eq!(call["callee"]["computed"], true);
+    assert_eq!(call["callee"]["optional"], true);
+
+    for source in ["x = a ?? b || c;", "x = a && b ?? c;", "x = a ?? b && c;"] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::MixedNullishCoalescing], "{source}");
+    }
+    for source in ["x = (a || b) ?? c;", "x = a ?? (b && c);", "x = a ?? b | c;"] {
+        assert!(parse(source).1.is_empty(), "{source}");
+    }
+    for source in ["a?.b`t`;", "new a?.b();"] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::InvalidOptionalChain], "{source}");
+    }
+}
+
+#[test]
+fn new_this_super_meta_and_import_expressions() {
+    let (ast, errors) = parse("new Foo(1); new a.b.C; new f()(); x = null; y = undefined;");
+    assert!(errors.is_empty());
+    let body = &ast["body"];
+    assert_eq!(body[0]["expression"]["type"], "NewExpression");
+    assert_eq!(body[0]["expression"]["arguments"][0]["value"], 1.0);
+    // `new` leva os acessos a membros, mas não a chamada seguinte
+    assert_eq!(body[1]["expression"]["callee"]["type"], "MemberExpression");
+    assert_eq!(body[2]["expression"]["type"], "CallExpression");
+    assert_eq!(body[2]["expression"]["callee"]["type"], "NewExpression");
+    assert_eq!(body[3]["expression"]["right"]["type"], "Literal");
+    assert!(body[3]["expression"]["right"]["value"].is_null());
+    assert_eq!(body[4]["expression"]["right"]["type"], "Identifier");
+
+    let (ast, errors) = parse(
+        "class A extends B { constructor() { super(); this.x = super.y; } }
+         await import(\"./x\");
+         import.meta.url;
+         function F() { new.target; }",
+    );
+    assert!(errors.is_empty());
+    assert_eq!(find(&ast, "CallExpression").unwrap()["callee"]["type"], "Super");
+    assert!(find(&ast, "ThisExpression").is_some());
+    assert_eq!(find(&ast, "ImportExpression").unwrap()["source"]["value"], "./x");
+    let meta: Vec<_> = [&ast["body"][2]["expression"]["object"], &ast["body"][3]["body"]["body"][0]["expression"]]
+        .iter()
+        .map(|node| format!("{}.{}", node["meta"]["name"].as_str().unwrap(), node["property"]["name"].as_str().unwrap()))
+        .collect();
+    assert_eq!(meta, ["import.meta", "new.target"]);
+
+    for source in ["new.foo;", "import.foo;"] {
+        assert_eq!(codes(&parse(source).1), [ErrorCode::InvalidMetaProperty], "{source}");
+    }
+}